@@ -16,20 +16,108 @@
 
 //! A collection of LED chasers on top of `smart_leds`.
 
+mod breathe;
+mod crossfade;
+mod looping;
+mod meteor;
+mod playback;
+mod playlist;
 mod rainbow_chaser;
+mod scanner;
+mod still;
+mod strobe;
+mod theater_chase;
+mod timeline;
 mod unicolor_transition;
+mod wipe;
 
+#[cfg(feature = "rand")]
+mod dissolve;
+#[cfg(feature = "rand")]
+mod fire;
+#[cfg(feature = "rand")]
+mod random_main_color;
 #[cfg(feature = "rand")]
 mod random_unicolor;
+#[cfg(feature = "rand")]
+mod twinkle;
 
+pub use breathe::{Breathe, BreatheConfig, Waveform};
+pub use crossfade::Crossfade;
+#[cfg(feature = "rand")]
+pub use dissolve::{Dissolve, DissolveConfig};
+#[cfg(feature = "rand")]
+pub use fire::{Fire, FireConfig, HEAT_PALETTE};
+pub use looping::{LoopMode, Looping};
+pub use meteor::{Meteor, MeteorConfig};
+pub use playlist::{PlaybackMode, Playlist, PlaylistEntry};
 pub use rainbow_chaser::RainbowChaser;
 #[cfg(feature = "rand")]
-pub use random_unicolor::RandomUnicolor;
+pub use random_main_color::RandomMainColor;
+#[cfg(feature = "rand")]
+pub use random_unicolor::{RandomUnicolor, TransitionTimeConfig};
+pub use scanner::{Scanner, ScannerConfig};
+pub use still::Still;
+pub use strobe::{Strobe, StrobeConfig};
+pub use theater_chase::{TheaterChase, TheaterChaseConfig};
+pub use timeline::{Keyframe, Timeline};
+#[cfg(feature = "rand")]
+pub use twinkle::{Twinkle, TwinkleConfig};
 pub use unicolor_transition::UnicolorTransition;
+pub use wipe::{Wipe, WipeOrigin};
 
 use crate::time::TimeConfig;
 
+/// The direction of a chaser.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// From the first LED to the last one.
+    #[default]
+    Forward,
+    /// From the last LED to the first one.
+    Backward,
+}
+
 /// A LED chaser.
 pub trait Chaser<const N: usize>: Iterator {
     fn set_time_config(&mut self, time_config: &TimeConfig);
+
+    /// Restarts the chaser from its beginning.
+    ///
+    /// Endless chasers can keep the default implementation, which does
+    /// nothing.
+    fn restart(&mut self) {}
+}
+
+/// A LED chaser made of a given number of steps.
+pub trait FiniteChaser<const N: usize>: Chaser<N> {
+    /// Returns the number of steps of the chaser.
+    fn step_number(&self) -> u32;
+
+    /// Moves to the given step.
+    fn set_step(&mut self, step: u32);
+}
+
+/// A LED chaser whose playback can be controlled.
+///
+/// Progresses are expressed from 0 to [`ONE`](crate::easing::ONE), and speed
+/// multipliers in the same scale, `ONE` being the normal speed.
+pub trait ControllableChaser<const N: usize>: Chaser<N> {
+    /// Pauses the chaser, which then keeps yielding the same frame.
+    fn pause(&mut self);
+
+    /// Resumes the chaser.
+    fn resume(&mut self);
+
+    /// Returns whether the chaser is paused.
+    fn is_paused(&self) -> bool;
+
+    /// Moves to the given progress.
+    fn seek(&mut self, progress: i32);
+
+    /// Returns the current progress.
+    fn progress(&self) -> i32;
+
+    /// Sets the speed multiplier.
+    fn set_speed(&mut self, speed: u32);
 }
@@ -0,0 +1,161 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use smart_leds::RGB8;
+
+use super::Chaser;
+use crate::{
+    color::{interpolate, Interpolation},
+    sequence::{Frame, FrameConfig, Sequence},
+    time::TimeConfig,
+};
+
+/// A chaser that modulates the brightness of a sequence, like a breath.
+///
+/// The transition time of the timing configuration is the duration of a
+/// breath.
+pub struct Breathe<S: Sequence<N>, const N: usize> {
+    /// The sequence configuration.
+    sequence_config: S::Config,
+    /// The configuration.
+    config: BreatheConfig,
+    /// The number of steps in a breath.
+    step_number: u32,
+    /// The current step.
+    step: u32,
+}
+
+/// The configuration for breathe chasers.
+#[derive(Debug, Clone, Copy)]
+pub struct BreatheConfig {
+    /// The shape of the breath.
+    pub waveform: Waveform,
+    /// The minimum brightness.
+    pub min_brightness: u8,
+    /// The maximum brightness.
+    pub max_brightness: u8,
+}
+
+/// The shape of a breath.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Waveform {
+    /// A smooth sine wave.
+    Sine,
+    /// A linear rise and fall.
+    Triangle,
+    /// An exponential rise and fall, which looks linear to the eye.
+    Exponential,
+}
+
+impl<S: Sequence<N, Item = RGB8>, const N: usize> Chaser<N> for Breathe<S, N> {
+    fn set_time_config(&mut self, time_config: &TimeConfig) {
+        let step_number = time_config.transition_steps().max(1);
+        self.step = self.step * step_number / self.step_number;
+        self.step_number = step_number;
+    }
+}
+
+impl<S: Sequence<N, Item = RGB8>, const N: usize> Breathe<S, N> {
+    /// Builds a new breathe chaser.
+    pub fn new(
+        sequence_config: S::Config,
+        config: BreatheConfig,
+        time_config: &TimeConfig,
+    ) -> Self {
+        Self {
+            sequence_config,
+            config,
+            step_number: time_config.transition_steps().max(1),
+            step: 0,
+        }
+    }
+
+    /// Sets the sequence configuration.
+    pub fn set_sequence_config(&mut self, sequence_config: S::Config) {
+        self.sequence_config = sequence_config;
+    }
+
+    /// Sets the configuration.
+    pub fn set_config(&mut self, config: BreatheConfig) {
+        self.config = config;
+    }
+}
+
+impl<S: Sequence<N, Item = RGB8>, const N: usize> Iterator for Breathe<S, N> {
+    type Item = Frame<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step == self.step_number {
+            self.step = 0;
+        }
+
+        let phase = (self.step as u64 * 256 / self.step_number as u64) as u32;
+        let level = waveform_level(self.config.waveform, phase);
+
+        let min = self.config.min_brightness as u32;
+        let max = self.config.max_brightness as u32;
+        let brightness = if max >= min {
+            min + (max - min) * level / 255
+        } else {
+            min - (min - max) * level / 255
+        };
+
+        let mut colors = [RGB8::default(); N];
+        for (color, sequence_color) in
+            colors.iter_mut().zip(S::new(self.sequence_config))
+        {
+            *color = interpolate(
+                RGB8::default(),
+                sequence_color,
+                brightness,
+                255,
+                Interpolation::Rgb,
+            );
+        }
+
+        self.step += 1;
+
+        Some(Frame::new(FrameConfig { colors }))
+    }
+}
+
+/// Computes the level of a waveform, from 0 to 255, for a phase from 0 to 255.
+fn waveform_level(waveform: Waveform, phase: u32) -> u32 {
+    match waveform {
+        Waveform::Sine => {
+            // NOTE: sin(π × phase / 256) is computed with Bhaskara I’s
+            // approximation, then squared to get the raised cosine.
+            let x = phase * (256 - phase);
+            let sine = 16 * x * 256 / (5 * 65536 - 4 * x);
+            (sine * sine / 256).min(255)
+        }
+        Waveform::Triangle => triangle(phase),
+        Waveform::Exponential => {
+            // NOTE: 2^(8 × t) - 1 with t from 0 to 1, 2^x being approximated
+            // linearly between integer values.
+            let t = triangle(phase);
+            ((1 << (t / 32)) * (32 + t % 32) / 32 - 1).min(255)
+        }
+    }
+}
+
+/// Computes a triangle wave, from 0 to 255, for a phase from 0 to 255.
+fn triangle(phase: u32) -> u32 {
+    if phase < 128 {
+        phase * 2
+    } else {
+        (255 - phase) * 2 + 1
+    }
+}
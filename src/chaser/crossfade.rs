@@ -0,0 +1,129 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use smart_leds::RGB8;
+
+use super::{Chaser, FiniteChaser};
+use crate::{
+    color::{interpolate, Interpolation},
+    sequence::{Frame, FrameConfig, Sequence},
+    time::TimeConfig,
+};
+
+/// A transition that blends a sequence into another one.
+pub struct Crossfade<A: Sequence<N>, B: Sequence<N>, const N: usize> {
+    /// The configuration of the start sequence.
+    start_config: A::Config,
+    /// The configuration of the end sequence.
+    end_config: B::Config,
+    /// The color interpolation method.
+    interpolation: Interpolation,
+    /// The number of steps to perform the transition.
+    step_number: u32,
+    /// The current step.
+    step: u32,
+}
+
+impl<
+        A: Sequence<N, Item = RGB8>,
+        B: Sequence<N, Item = RGB8>,
+        const N: usize,
+    > Chaser<N> for Crossfade<A, B, N>
+{
+    fn set_time_config(&mut self, time_config: &TimeConfig) {
+        let step_number = time_config.transition_steps().max(1);
+        self.step = self.step * step_number / self.step_number;
+        self.step_number = step_number;
+    }
+
+    fn restart(&mut self) {
+        self.step = 0;
+    }
+}
+
+impl<
+        A: Sequence<N, Item = RGB8>,
+        B: Sequence<N, Item = RGB8>,
+        const N: usize,
+    > FiniteChaser<N> for Crossfade<A, B, N>
+{
+    fn step_number(&self) -> u32 {
+        self.step_number
+    }
+
+    fn set_step(&mut self, step: u32) {
+        self.step = step.min(self.step_number);
+    }
+}
+
+impl<
+        A: Sequence<N, Item = RGB8>,
+        B: Sequence<N, Item = RGB8>,
+        const N: usize,
+    > Crossfade<A, B, N>
+{
+    /// Builds a new crossfade transition.
+    pub fn new(
+        start_config: A::Config,
+        end_config: B::Config,
+        time_config: &TimeConfig,
+    ) -> Self {
+        Self {
+            start_config,
+            end_config,
+            interpolation: Interpolation::default(),
+            step_number: time_config.transition_steps().max(1),
+            step: 0,
+        }
+    }
+
+    /// Sets the color interpolation method.
+    pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+        self.interpolation = interpolation;
+    }
+}
+
+impl<
+        A: Sequence<N, Item = RGB8>,
+        B: Sequence<N, Item = RGB8>,
+        const N: usize,
+    > Iterator for Crossfade<A, B, N>
+{
+    type Item = Frame<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step < self.step_number {
+            self.step += 1;
+
+            let mut colors = [RGB8::default(); N];
+            for (color, (start_color, end_color)) in colors
+                .iter_mut()
+                .zip(A::new(self.start_config).zip(B::new(self.end_config)))
+            {
+                *color = interpolate(
+                    start_color,
+                    end_color,
+                    self.step,
+                    self.step_number,
+                    self.interpolation,
+                );
+            }
+
+            Some(Frame::new(FrameConfig { colors }))
+        } else {
+            None
+        }
+    }
+}
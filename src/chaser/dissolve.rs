@@ -0,0 +1,171 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use embedded_time::duration::Generic;
+use rand::{rngs::SmallRng, seq::SliceRandom, SeedableRng};
+use smart_leds::RGB8;
+
+use super::{Chaser, FiniteChaser};
+use crate::{
+    color::{interpolate, Interpolation},
+    sequence::{Frame, FrameConfig, Sequence},
+    time::{self, TimeConfig},
+};
+
+/// A transition in which the LEDs switch from a sequence to another one in a
+/// random order.
+pub struct Dissolve<A: Sequence<N>, B: Sequence<N>, const N: usize> {
+    /// The configuration of the start sequence.
+    start_config: A::Config,
+    /// The configuration of the end sequence.
+    end_config: B::Config,
+    /// The configuration.
+    config: DissolveConfig,
+    /// The rank of each LED in the switching order.
+    ranks: [u32; N],
+    /// The number of steps for a LED to fade.
+    fade_steps: u32,
+    /// The number of steps to perform the transition.
+    step_number: u32,
+    /// The current step.
+    step: u32,
+}
+
+/// The configuration for dissolve transitions.
+#[derive(Debug, Clone, Copy)]
+pub struct DissolveConfig {
+    /// The seed of the switching order.
+    pub seed: u64,
+    /// The duration for each LED to fade from a sequence to the other.
+    pub fade_time: Generic<u32>,
+}
+
+impl<
+        A: Sequence<N, Item = RGB8>,
+        B: Sequence<N, Item = RGB8>,
+        const N: usize,
+    > Chaser<N> for Dissolve<A, B, N>
+{
+    fn set_time_config(&mut self, time_config: &TimeConfig) {
+        let step_number = time_config.transition_steps().max(1);
+        self.step = self.step * step_number / self.step_number;
+        self.step_number = step_number;
+        self.fade_steps =
+            time::steps(time_config.refresh_rate, self.config.fade_time)
+                .min(step_number);
+    }
+
+    fn restart(&mut self) {
+        self.step = 0;
+    }
+}
+
+impl<
+        A: Sequence<N, Item = RGB8>,
+        B: Sequence<N, Item = RGB8>,
+        const N: usize,
+    > FiniteChaser<N> for Dissolve<A, B, N>
+{
+    fn step_number(&self) -> u32 {
+        self.step_number
+    }
+
+    fn set_step(&mut self, step: u32) {
+        self.step = step.min(self.step_number);
+    }
+}
+
+impl<
+        A: Sequence<N, Item = RGB8>,
+        B: Sequence<N, Item = RGB8>,
+        const N: usize,
+    > Dissolve<A, B, N>
+{
+    /// Builds a new dissolve transition.
+    pub fn new(
+        start_config: A::Config,
+        end_config: B::Config,
+        config: DissolveConfig,
+        time_config: &TimeConfig,
+    ) -> Self {
+        let mut rng = SmallRng::seed_from_u64(config.seed);
+
+        let mut ranks = [0; N];
+        for (rank, i) in ranks.iter_mut().zip(0..) {
+            *rank = i;
+        }
+        ranks.shuffle(&mut rng);
+
+        let step_number = time_config.transition_steps().max(1);
+
+        Self {
+            start_config,
+            end_config,
+            config,
+            ranks,
+            fade_steps: time::steps(time_config.refresh_rate, config.fade_time)
+                .min(step_number),
+            step_number,
+            step: 0,
+        }
+    }
+}
+
+impl<
+        A: Sequence<N, Item = RGB8>,
+        B: Sequence<N, Item = RGB8>,
+        const N: usize,
+    > Iterator for Dissolve<A, B, N>
+{
+    type Item = Frame<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step < self.step_number {
+            self.step += 1;
+
+            let switch_steps = (self.step_number - self.fade_steps) as u64;
+
+            let mut colors = [RGB8::default(); N];
+            for ((color, rank), (start_color, end_color)) in colors
+                .iter_mut()
+                .zip(self.ranks)
+                .zip(A::new(self.start_config).zip(B::new(self.end_config)))
+            {
+                // NOTE: The last LED starts to fade so that its fade ends with
+                // the transition.
+                let switch_step =
+                    ((rank as u64 + 1) * switch_steps / N as u64) as u32;
+
+                *color = if self.step < switch_step {
+                    start_color
+                } else if self.step - switch_step >= self.fade_steps {
+                    end_color
+                } else {
+                    interpolate(
+                        start_color,
+                        end_color,
+                        self.step - switch_step,
+                        self.fade_steps,
+                        Interpolation::Rgb,
+                    )
+                };
+            }
+
+            Some(Frame::new(FrameConfig { colors }))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,167 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use smart_leds::RGB8;
+
+use super::Chaser;
+use crate::{
+    sequence::{
+        ColorStop, Frame, FrameConfig, PaletteGradientConfig, Sequence,
+    },
+    time::TimeConfig,
+};
+
+/// A palette going from black to white through red and yellow.
+pub const HEAT_PALETTE: PaletteGradientConfig<4> = PaletteGradientConfig {
+    stops: [
+        ColorStop {
+            position: 0,
+            color: RGB8 { r: 0, g: 0, b: 0 },
+        },
+        ColorStop {
+            position: 85,
+            color: RGB8 { r: 255, g: 0, b: 0 },
+        },
+        ColorStop {
+            position: 170,
+            color: RGB8 {
+                r: 255,
+                g: 255,
+                b: 0,
+            },
+        },
+        ColorStop {
+            position: 255,
+            color: RGB8 {
+                r: 255,
+                g: 255,
+                b: 255,
+            },
+        },
+    ],
+};
+
+/// The number of LEDs at the base of the fire in which sparks can be lit.
+const SPARK_HEIGHT: usize = 7;
+
+/// A chaser that simulates a fire, starting from the first LED.
+///
+/// The transition time of the timing configuration is the duration of a step
+/// of the simulation. A zero transition time performs one step per frame.
+pub struct Fire<const N: usize, const P: usize> {
+    /// The random number generator for cooling and sparks.
+    rng: SmallRng,
+    /// The configuration.
+    config: FireConfig<P>,
+    /// The heat of each LED.
+    heat: [u8; N],
+    /// The refresh rate, in Hz.
+    refresh_rate: u32,
+    /// The duration of a simulation step, in ms.
+    step_time_ms: u32,
+    /// The time elapsed since the last simulation step, in ms × Hz.
+    elapsed: u32,
+}
+
+/// The configuration for fire chasers.
+#[derive(Debug, Clone, Copy)]
+pub struct FireConfig<const P: usize> {
+    /// How much the air cools down at each step, from 0 (tall flames) to 255
+    /// (short flames).
+    pub cooling: u8,
+    /// The chance, out of 255, that a new spark is lit at each step.
+    pub sparking: u8,
+    /// The palette mapping heat to colors.
+    pub palette: PaletteGradientConfig<P>,
+}
+
+impl<const N: usize, const P: usize> Chaser<N> for Fire<N, P> {
+    fn set_time_config(&mut self, time_config: &TimeConfig) {
+        self.refresh_rate = time_config.refresh_rate.0;
+        self.step_time_ms = time_config.transition_time_ms();
+    }
+}
+
+impl<const N: usize, const P: usize> Fire<N, P> {
+    /// Builds a new fire chaser.
+    pub fn new(config: FireConfig<P>, time_config: &TimeConfig) -> Self {
+        Self {
+            rng: SmallRng::seed_from_u64(0),
+            config,
+            heat: [0; N],
+            refresh_rate: time_config.refresh_rate.0,
+            step_time_ms: time_config.transition_time_ms(),
+            elapsed: 0,
+        }
+    }
+
+    /// Sets the configuration.
+    pub fn set_config(&mut self, config: FireConfig<P>) {
+        self.config = config;
+    }
+
+    /// Performs a step of the simulation.
+    fn step(&mut self) {
+        // Cool down every cell a little.
+        let max_cooling =
+            (self.config.cooling as usize * 10 / N.max(1) + 2).min(255);
+        for heat in self.heat.iter_mut() {
+            let cooling = self.rng.gen_range(0..max_cooling) as u8;
+            *heat = heat.saturating_sub(cooling);
+        }
+
+        // Let the heat drift up and diffuse a little.
+        for i in (2..N).rev() {
+            self.heat[i] = ((self.heat[i - 1] as u16
+                + 2 * self.heat[i - 2] as u16)
+                / 3) as u8;
+        }
+
+        // Randomly ignite new sparks near the bottom.
+        if N > 0 && self.rng.gen::<u8>() < self.config.sparking {
+            let i = self.rng.gen_range(0..SPARK_HEIGHT.min(N));
+            let spark = self.rng.gen_range(160..=255);
+            self.heat[i] = self.heat[i].saturating_add(spark);
+        }
+    }
+}
+
+impl<const N: usize, const P: usize> Iterator for Fire<N, P> {
+    type Item = Frame<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // NOTE: Time is counted in ms × Hz so that frame durations are exact.
+        let step_cost = self.step_time_ms * self.refresh_rate;
+
+        if step_cost == 0 {
+            // Without a step time, perform one step per frame.
+            self.step();
+        } else {
+            self.elapsed += 1000;
+            while self.elapsed >= step_cost {
+                self.step();
+                self.elapsed -= step_cost;
+            }
+        }
+
+        let mut colors = [RGB8::default(); N];
+        for (color, heat) in colors.iter_mut().zip(self.heat) {
+            *color = self.config.palette.color_at(heat);
+        }
+
+        Some(Frame::new(FrameConfig { colors }))
+    }
+}
@@ -0,0 +1,229 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{Chaser, FiniteChaser};
+use crate::time::TimeConfig;
+
+/// A chaser that plays a finite chaser multiple times.
+pub struct Looping<C: FiniteChaser<N>, const N: usize> {
+    /// The underlying chaser.
+    chaser: C,
+    /// The loop mode.
+    mode: LoopMode,
+    /// The next step of the underlying chaser to play.
+    step: u32,
+    /// Whether the chaser is played forwards.
+    forward: bool,
+    /// The number of passes already played.
+    passes: u32,
+    /// Whether the loop is finished.
+    finished: bool,
+}
+
+/// The way of looping a finite chaser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoopMode {
+    /// Plays the chaser once.
+    Once,
+    /// Plays the chaser a given number of times.
+    Repeat(u32),
+    /// Plays the chaser forever.
+    Forever,
+    /// Plays the chaser forwards then backwards, forever.
+    PingPong,
+}
+
+impl<C: FiniteChaser<N>, const N: usize> Chaser<N> for Looping<C, N> {
+    fn set_time_config(&mut self, time_config: &TimeConfig) {
+        let step_number = self.chaser.step_number();
+        self.chaser.set_time_config(time_config);
+        self.step = self.step * self.chaser.step_number() / step_number.max(1);
+    }
+
+    fn restart(&mut self) {
+        self.chaser.restart();
+        self.step = 0;
+        self.forward = true;
+        self.passes = 0;
+        self.finished = false;
+    }
+}
+
+impl<C: FiniteChaser<N>, const N: usize> Looping<C, N> {
+    /// Builds a new looping chaser.
+    pub fn new(chaser: C, mode: LoopMode) -> Self {
+        Self {
+            chaser,
+            mode,
+            step: 0,
+            forward: true,
+            passes: 0,
+            finished: false,
+        }
+    }
+
+    /// Sets the loop mode.
+    pub fn set_mode(&mut self, mode: LoopMode) {
+        self.mode = mode;
+        self.finished = false;
+    }
+
+    /// Returns whether the loop is finished.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Returns the number of passes already played.
+    pub fn passes(&self) -> u32 {
+        self.passes
+    }
+
+    /// Returns the underlying chaser.
+    pub fn chaser(&self) -> &C {
+        &self.chaser
+    }
+
+    /// Returns the underlying chaser, mutably.
+    pub fn chaser_mut(&mut self) -> &mut C {
+        &mut self.chaser
+    }
+}
+
+impl<C: FiniteChaser<N>, const N: usize> Iterator for Looping<C, N> {
+    type Item = C::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let step_number = self.chaser.step_number();
+        let end_of_pass = if self.forward {
+            self.step >= step_number
+        } else {
+            self.step == 0
+        };
+
+        if end_of_pass {
+            self.passes += 1;
+
+            match self.mode {
+                LoopMode::Once => self.finished = true,
+                LoopMode::Repeat(count) if self.passes >= count => {
+                    self.finished = true
+                }
+                LoopMode::Repeat(_) | LoopMode::Forever => self.step = 0,
+                // NOTE: With two steps or less, there is no step to play
+                // backwards, so the chaser is simply restarted.
+                LoopMode::PingPong if step_number <= 2 => {
+                    self.forward = true;
+                    self.step = 0;
+                }
+                LoopMode::PingPong => {
+                    // NOTE: The steps at both ends are not played twice.
+                    self.forward = !self.forward;
+                    if !self.forward {
+                        self.step = step_number.saturating_sub(2);
+                    }
+                }
+            }
+
+            if self.finished {
+                return None;
+            }
+        }
+
+        self.chaser.set_step(self.step);
+        let item = self.chaser.next();
+
+        if self.forward {
+            self.step += 1;
+        } else {
+            self.step = self.step.saturating_sub(1);
+        }
+
+        item
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A finite chaser yielding its steps.
+    struct Steps {
+        step_number: u32,
+        step: u32,
+    }
+
+    impl Chaser<1> for Steps {
+        fn set_time_config(&mut self, _time_config: &TimeConfig) {}
+    }
+
+    impl FiniteChaser<1> for Steps {
+        fn step_number(&self) -> u32 {
+            self.step_number
+        }
+
+        fn set_step(&mut self, step: u32) {
+            self.step = step.min(self.step_number);
+        }
+    }
+
+    impl Iterator for Steps {
+        type Item = u32;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.step < self.step_number {
+                self.step += 1;
+                Some(self.step - 1)
+            } else {
+                None
+            }
+        }
+    }
+
+    fn ping_pong(step_number: u32) -> Vec<Option<u32>> {
+        let steps = Steps {
+            step_number,
+            step: 0,
+        };
+
+        let mut looping = Looping::new(steps, LoopMode::PingPong);
+        (0..8).map(|_| looping.next()).collect()
+    }
+
+    #[test]
+    fn ping_pong_plays_back_and_forth() {
+        let expected = [0, 1, 2, 3, 2, 1, 0, 1].map(Some);
+        assert_eq!(ping_pong(4), expected);
+    }
+
+    #[test]
+    fn ping_pong_with_two_steps_restarts() {
+        let expected = [0, 1, 0, 1, 0, 1, 0, 1].map(Some);
+        assert_eq!(ping_pong(2), expected);
+    }
+
+    #[test]
+    fn ping_pong_with_one_step_repeats_it() {
+        assert_eq!(ping_pong(1), [Some(0); 8]);
+    }
+
+    #[test]
+    fn ping_pong_with_no_step_yields_nothing() {
+        assert_eq!(ping_pong(0), [None; 8]);
+    }
+}
@@ -0,0 +1,215 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(feature = "rand")]
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use smart_leds::RGB8;
+
+use super::{Chaser, Direction};
+use crate::{
+    color::{interpolate, Interpolation},
+    sequence::{ConfigWithMainColor, Frame, FrameConfig, Sequence},
+    time::TimeConfig,
+};
+
+/// A chaser in which a meteor moves along the LEDs, leaving a decaying tail.
+///
+/// The transition time of the timing configuration is the duration for the
+/// meteor to go through all the LEDs.
+pub struct Meteor<const N: usize> {
+    /// The configuration.
+    config: MeteorConfig,
+    /// The number of steps to go through all the LEDs.
+    step_number: u32,
+    /// The current step.
+    step: u32,
+    /// The random number generator for tail decay.
+    #[cfg(feature = "rand")]
+    rng: SmallRng,
+    /// The age of the tail on each LED at the previous step.
+    #[cfg(feature = "rand")]
+    ages: [u32; N],
+    /// The decay speed factor of the tail on each LED, 128 being neutral.
+    #[cfg(feature = "rand")]
+    decay_factors: [u8; N],
+}
+
+/// The configuration for meteor chasers.
+#[derive(Debug, Clone, Copy)]
+pub struct MeteorConfig {
+    /// The color of the meteor.
+    pub color: RGB8,
+    /// The length of the tail, in LEDs.
+    pub tail_length: u32,
+    /// The direction of the meteor.
+    pub direction: Direction,
+    /// Whether the meteor bounces at the end instead of wrapping around.
+    pub bounce: bool,
+    /// The random variation of the tail decay, from 0 (none) to 255.
+    ///
+    /// This is ignored without the `rand` feature.
+    pub random_decay: u8,
+}
+
+impl<const N: usize> Chaser<N> for Meteor<N> {
+    fn set_time_config(&mut self, time_config: &TimeConfig) {
+        let step_number = time_config.transition_steps().max(1);
+        self.step = self.step * step_number / self.step_number;
+        self.step_number = step_number;
+    }
+}
+
+impl<const N: usize> Meteor<N> {
+    /// Builds a new meteor chaser.
+    pub fn new(config: MeteorConfig, time_config: &TimeConfig) -> Self {
+        Self {
+            config,
+            step_number: time_config.transition_steps().max(1),
+            step: 0,
+            #[cfg(feature = "rand")]
+            rng: SmallRng::seed_from_u64(0),
+            #[cfg(feature = "rand")]
+            ages: [u32::MAX; N],
+            #[cfg(feature = "rand")]
+            decay_factors: [128; N],
+        }
+    }
+
+    /// Sets the configuration.
+    pub fn set_config(&mut self, config: MeteorConfig) {
+        self.config = config;
+    }
+
+    /// Computes the distance travelled by the meteor since the beginning of
+    /// the cycle, in 1/256 of LED, on a whole LED.
+    fn head_position(&self, span: u32) -> u32 {
+        let position = self.step as u64 * span as u64 / self.step_number as u64;
+        position as u32 / 256 * 256
+    }
+
+    /// Computes the age of the tail on each LED, in 1/256 of LED travelled by
+    /// the meteor since it passed the LED.
+    fn tail_ages(&self) -> [u32; N] {
+        let mut ages = [0; N];
+
+        if self.config.bounce {
+            let span = N.saturating_sub(1) as u32 * 256;
+            let position = self.head_position(span);
+
+            for (i, age) in ages.iter_mut().enumerate() {
+                let x = i as u32 * 256;
+                *age = if position <= span {
+                    if x <= position {
+                        position - x
+                    } else {
+                        position + x
+                    }
+                } else if x >= 2 * span - position {
+                    position + x - 2 * span
+                } else {
+                    position - x
+                };
+            }
+        } else {
+            let span = N as u32 * 256;
+            let position = self.head_position(span);
+
+            for (i, age) in ages.iter_mut().enumerate() {
+                *age = (position + span - i as u32 * 256) % span;
+            }
+        }
+
+        ages
+    }
+}
+
+impl<const N: usize> Iterator for Meteor<N> {
+    type Item = Frame<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cycle = if self.config.bounce {
+            2 * self.step_number
+        } else {
+            self.step_number
+        };
+
+        if self.step >= cycle {
+            self.step = 0;
+        }
+
+        #[allow(unused_mut)]
+        let mut ages = self.tail_ages();
+
+        #[cfg(feature = "rand")]
+        for ((age, last_age), decay_factor) in ages
+            .iter_mut()
+            .zip(self.ages.iter_mut())
+            .zip(self.decay_factors.iter_mut())
+        {
+            // Pick a new decay factor each time the meteor passes.
+            if *age < *last_age {
+                let variation = self.config.random_decay / 2;
+                *decay_factor =
+                    self.rng.gen_range(128 - variation..=128 + variation);
+            }
+
+            *last_age = *age;
+            *age = *age * *decay_factor as u32 / 128;
+        }
+
+        let mut colors = [RGB8::default(); N];
+        for (color, age) in colors.iter_mut().zip(ages) {
+            *color = interpolate(
+                RGB8::default(),
+                self.config.color,
+                decay(age, self.config.tail_length) as u32,
+                255,
+                Interpolation::Rgb,
+            );
+        }
+
+        if self.config.direction == Direction::Backward {
+            colors.reverse();
+        }
+
+        self.step += 1;
+
+        Some(Frame::new(FrameConfig { colors }))
+    }
+}
+
+impl ConfigWithMainColor for MeteorConfig {
+    fn main_color(&self) -> RGB8 {
+        self.color
+    }
+
+    fn set_main_color(&mut self, color: RGB8) {
+        self.color = color;
+    }
+}
+
+/// Computes the brightness of the tail, halving every eighth of its length.
+fn decay(age: u32, tail_length: u32) -> u8 {
+    // The number of halvings, in 1/256.
+    let halvings = age as u64 * 8 / tail_length.max(1) as u64;
+
+    if halvings >= 8 * 256 {
+        0
+    } else {
+        // NOTE: 2^(-x) is approximated linearly between integer values.
+        let fraction = halvings % 256;
+        ((255 >> (halvings / 256)) * (512 - fraction) / 512) as u8
+    }
+}
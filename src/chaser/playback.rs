@@ -0,0 +1,81 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::easing::ONE;
+
+/// The playback state of a controllable chaser.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Playback {
+    /// Whether the playback is paused.
+    paused: bool,
+    /// The speed multiplier, `ONE` being the normal speed.
+    speed: u32,
+    /// The fraction of step accumulated since the last step.
+    remainder: u32,
+}
+
+impl Default for Playback {
+    fn default() -> Self {
+        Self {
+            paused: false,
+            speed: ONE as u32,
+            remainder: 0,
+        }
+    }
+}
+
+impl Playback {
+    /// Pauses the playback.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes the playback.
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Returns whether the playback is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Sets the speed multiplier.
+    pub fn set_speed(&mut self, speed: u32) {
+        self.speed = speed;
+    }
+
+    /// Returns the number of steps to advance after a frame.
+    pub fn advance(&mut self) -> u32 {
+        if self.paused {
+            return 0;
+        }
+
+        let total = self.remainder + self.speed;
+        self.remainder = total % ONE as u32;
+        total / ONE as u32
+    }
+}
+
+/// Converts a step into a progress, from 0 to `ONE`.
+pub(super) fn step_to_progress(step: u32, step_number: u32) -> i32 {
+    (step.min(step_number) as i64 * ONE as i64 / step_number.max(1) as i64)
+        as i32
+}
+
+/// Converts a progress, from 0 to `ONE`, into a step.
+pub(super) fn progress_to_step(progress: i32, step_number: u32) -> u32 {
+    (progress.clamp(0, ONE) as i64 * step_number as i64 / ONE as i64) as u32
+}
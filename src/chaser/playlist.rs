@@ -0,0 +1,278 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use embedded_time::{duration::Generic, rate::Hertz};
+#[cfg(feature = "rand")]
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use smart_leds::RGB8;
+
+use super::Chaser;
+use crate::{
+    color::{interpolate, Interpolation},
+    sequence::{Frame, FrameConfig, Sequence},
+    time::{self, TimeConfig},
+};
+
+/// A chaser that plays a list of chasers one after the other.
+///
+/// To play chasers of different types, wrap them in an enum implementing
+/// [`Chaser`]. Sequences can be played with a [`Still`](super::Still) chaser.
+///
+/// Each chaser is restarted with [`Chaser::restart`] when played again, so
+/// that finite chasers play from their beginning while endless ones resume.
+/// When a chaser ends before the duration of its entry, its last frame is held.
+///
+/// The transition time of the timing configuration is the duration of the
+/// crossfade between two entries, which can be zero. A crossfade lasts at most
+/// half of the shorter of the two entries, so that it never overlaps another
+/// one.
+pub struct Playlist<E, const N: usize, const M: usize> {
+    /// The entries.
+    entries: [PlaylistEntry<E>; M],
+    /// The playback mode.
+    mode: PlaybackMode,
+    /// The refresh rate.
+    refresh_rate: Hertz,
+    /// The number of steps of a crossfade.
+    fade_steps: u32,
+    /// The index of the current entry, `M` meaning the playlist is finished.
+    current: usize,
+    /// The index of the next entry.
+    next: Option<usize>,
+    /// Whether the next entry has started playing in a crossfade.
+    next_started: bool,
+    /// The current step in the current entry.
+    step: u32,
+    /// The last frame of the current entry.
+    current_frame: [RGB8; N],
+    /// The last frame of the next entry.
+    next_frame: [RGB8; N],
+    /// The random number generator for shuffling.
+    #[cfg(feature = "rand")]
+    rng: SmallRng,
+}
+
+/// An entry in a playlist.
+pub struct PlaylistEntry<E> {
+    /// The chaser to play.
+    pub chaser: E,
+    /// The duration of the entry.
+    pub duration: Generic<u32>,
+}
+
+/// The playback mode of a playlist.
+///
+/// The `Shuffle` mode is only available with the `rand` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PlaybackMode {
+    /// Plays the entries in order, then stops.
+    Once,
+    /// Plays the entries in order, forever.
+    Loop,
+    /// Plays the entries in a random order, forever.
+    #[cfg(feature = "rand")]
+    Shuffle,
+}
+
+impl<E, const N: usize, const M: usize> Chaser<N> for Playlist<E, N, M>
+where
+    E: Chaser<N>,
+    E::Item: IntoIterator<Item = RGB8>,
+{
+    fn set_time_config(&mut self, time_config: &TimeConfig) {
+        self.step =
+            self.step * time_config.refresh_rate.0 / self.refresh_rate.0.max(1);
+        self.refresh_rate = time_config.refresh_rate;
+        self.fade_steps = time_config.transition_steps();
+    }
+
+    fn restart(&mut self) {
+        self.current = 0;
+
+        #[cfg(feature = "rand")]
+        if self.mode == PlaybackMode::Shuffle {
+            self.current = self.rng.gen_range(0..M);
+        }
+
+        self.next = self.pick_next();
+        self.next_started = false;
+        self.step = 0;
+        self.entries[self.current].chaser.restart();
+    }
+}
+
+impl<E, const N: usize, const M: usize> Playlist<E, N, M>
+where
+    E: Chaser<N>,
+    E::Item: IntoIterator<Item = RGB8>,
+{
+    /// Builds a new playlist.
+    pub fn new(
+        entries: [PlaylistEntry<E>; M],
+        mode: PlaybackMode,
+        time_config: &TimeConfig,
+    ) -> Self {
+        assert!(M > 0);
+
+        let mut playlist = Self {
+            entries,
+            mode,
+            refresh_rate: time_config.refresh_rate,
+            fade_steps: time_config.transition_steps(),
+            current: 0,
+            next: None,
+            next_started: false,
+            step: 0,
+            current_frame: [RGB8::default(); N],
+            next_frame: [RGB8::default(); N],
+            #[cfg(feature = "rand")]
+            rng: SmallRng::seed_from_u64(0),
+        };
+
+        playlist.restart();
+        playlist
+    }
+
+    /// Returns the index of the current entry, if any.
+    pub fn current(&self) -> Option<usize> {
+        (self.current < M).then_some(self.current)
+    }
+
+    /// Returns the entries.
+    pub fn entries(&self) -> &[PlaylistEntry<E>; M] {
+        &self.entries
+    }
+
+    /// Returns the entries, mutably.
+    pub fn entries_mut(&mut self) -> &mut [PlaylistEntry<E>; M] {
+        &mut self.entries
+    }
+
+    /// Picks the entry to play after the current one.
+    fn pick_next(&mut self) -> Option<usize> {
+        match self.mode {
+            PlaybackMode::Once => {
+                (self.current + 1 < M).then_some(self.current + 1)
+            }
+            PlaybackMode::Loop => Some((self.current + 1) % M),
+            #[cfg(feature = "rand")]
+            PlaybackMode::Shuffle => {
+                if M == 1 {
+                    Some(0)
+                } else {
+                    // Pick any entry but the current one.
+                    let next = self.rng.gen_range(0..M - 1);
+                    Some(if next >= self.current { next + 1 } else { next })
+                }
+            }
+        }
+    }
+
+    /// Returns the number of steps of an entry.
+    fn entry_steps(&self, index: usize) -> u32 {
+        time::steps(self.refresh_rate, self.entries[index].duration).max(1)
+    }
+}
+
+impl<E, const N: usize, const M: usize> Iterator for Playlist<E, N, M>
+where
+    E: Chaser<N>,
+    E::Item: IntoIterator<Item = RGB8>,
+{
+    type Item = Frame<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current >= M {
+            return None;
+        }
+
+        let entry_steps = self.entry_steps(self.current);
+        let next = self.next.filter(|&next| next != self.current);
+        let fade_steps = match next {
+            Some(next) => self
+                .fade_steps
+                .min(entry_steps / 2)
+                .min(self.entry_steps(next) / 2),
+            None => 0,
+        };
+        let fade_start = entry_steps - fade_steps;
+
+        if let Some(sequence) = self.entries[self.current].chaser.next() {
+            store_frame(&mut self.current_frame, sequence);
+        }
+
+        let mut colors = self.current_frame;
+
+        // Crossfade with the next entry at the end of the current one.
+        if let Some(next) = next {
+            if fade_steps > 0 && self.step >= fade_start {
+                if !self.next_started {
+                    self.entries[next].chaser.restart();
+                    self.next_started = true;
+                }
+
+                if let Some(sequence) = self.entries[next].chaser.next() {
+                    store_frame(&mut self.next_frame, sequence);
+                }
+
+                for (color, next_color) in
+                    colors.iter_mut().zip(self.next_frame)
+                {
+                    *color = interpolate(
+                        *color,
+                        next_color,
+                        (self.step - fade_start + 1).min(fade_steps),
+                        fade_steps,
+                        Interpolation::Rgb,
+                    );
+                }
+            }
+        }
+
+        self.step += 1;
+
+        if self.step >= entry_steps {
+            match self.next {
+                Some(next) => {
+                    if self.next_started {
+                        self.current_frame = self.next_frame;
+                        self.step = fade_steps;
+                    } else {
+                        self.entries[next].chaser.restart();
+                        self.step = 0;
+                    }
+
+                    self.next_started = false;
+
+                    self.current = next;
+                    self.next = self.pick_next();
+                }
+                None => self.current = M,
+            }
+        }
+
+        Some(Frame::new(FrameConfig { colors }))
+    }
+}
+
+fn store_frame<const N: usize>(
+    frame: &mut [RGB8; N],
+    sequence: impl IntoIterator<Item = RGB8>,
+) {
+    for (led, color) in frame.iter_mut().zip(sequence) {
+        *led = color;
+    }
+}
@@ -16,18 +16,37 @@
 
 use smart_leds::hsv::Hsv;
 
-use super::Chaser;
+use super::{
+    playback::{progress_to_step, step_to_progress, Playback},
+    Chaser, ControllableChaser, Direction, FiniteChaser,
+};
 use crate::{
+    easing::{Easing, ONE},
     sequence::{ConfigWithMainColor, Sequence},
     time::TimeConfig,
 };
 
 /// A chaser that loops on the wheel of hues.
+///
+/// By default, the hue goes forward over the full wheel, starting from the
+/// main color of the sequence configuration.
 pub struct RainbowChaser<S: Sequence<N>, const N: usize> {
     /// The sequence configuration.
     sequence_config: S::Config,
     /// The start color.
     start_color: Hsv,
+    /// The span of the hue range, from the start hue.
+    hue_span: u8,
+    /// The direction of the hue changes.
+    direction: Direction,
+    /// Whether the hue goes back and forth in the hue range.
+    ping_pong: bool,
+    /// Whether the hue is going back in ping-pong mode.
+    returning: bool,
+    /// The easing curve of a loop.
+    easing: Easing,
+    /// The playback state.
+    playback: Playback,
     /// The number of steps in a loop.
     step_number: u32,
     /// The current step.
@@ -44,6 +63,46 @@ impl<S: Sequence<N, Config = impl ConfigWithMainColor>, const N: usize>
     }
 }
 
+impl<S: Sequence<N, Config = impl ConfigWithMainColor>, const N: usize>
+    FiniteChaser<N> for RainbowChaser<S, N>
+{
+    fn step_number(&self) -> u32 {
+        self.step_number
+    }
+
+    fn set_step(&mut self, step: u32) {
+        self.step = step.min(self.step_number);
+    }
+}
+
+impl<S: Sequence<N, Config = impl ConfigWithMainColor>, const N: usize>
+    ControllableChaser<N> for RainbowChaser<S, N>
+{
+    fn pause(&mut self) {
+        self.playback.pause();
+    }
+
+    fn resume(&mut self) {
+        self.playback.resume();
+    }
+
+    fn is_paused(&self) -> bool {
+        self.playback.is_paused()
+    }
+
+    fn seek(&mut self, progress: i32) {
+        self.step = progress_to_step(progress, self.step_number);
+    }
+
+    fn progress(&self) -> i32 {
+        step_to_progress(self.step, self.step_number)
+    }
+
+    fn set_speed(&mut self, speed: u32) {
+        self.playback.set_speed(speed);
+    }
+}
+
 impl<S: Sequence<N, Config = impl ConfigWithMainColor>, const N: usize>
     RainbowChaser<S, N>
 {
@@ -51,10 +110,44 @@ impl<S: Sequence<N, Config = impl ConfigWithMainColor>, const N: usize>
         Self {
             sequence_config,
             start_color: sequence_config.main_color().into(),
+            hue_span: 255,
+            direction: Direction::default(),
+            ping_pong: false,
+            returning: false,
+            easing: Easing::default(),
+            playback: Playback::default(),
             step_number: time_config.transition_steps(),
             step: 0,
         }
     }
+
+    /// Sets the easing curve of a loop.
+    pub fn set_easing(&mut self, easing: Easing) {
+        self.easing = easing;
+    }
+
+    /// Sets the direction of the hue changes.
+    pub fn set_direction(&mut self, direction: Direction) {
+        self.direction = direction;
+    }
+
+    /// Sets whether the hue goes back and forth in the hue range instead of
+    /// looping.
+    pub fn set_ping_pong(&mut self, ping_pong: bool) {
+        self.ping_pong = ping_pong;
+        self.returning = false;
+    }
+
+    /// Sets the range of hues, from `start` to `end` on the wheel.
+    ///
+    /// Equal hues give the full wheel.
+    pub fn set_hue_range(&mut self, start: u8, end: u8) {
+        self.start_color.hue = start;
+        self.hue_span = match end.wrapping_sub(start) {
+            0 => 255,
+            span => span,
+        };
+    }
 }
 
 impl<S: Sequence<N, Config = impl ConfigWithMainColor>, const N: usize> Iterator
@@ -63,17 +156,31 @@ impl<S: Sequence<N, Config = impl ConfigWithMainColor>, const N: usize> Iterator
     type Item = S;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.step == self.step_number {
-            self.step = 0;
+        if self.step >= self.step_number {
+            self.step %= self.step_number;
+            self.returning = self.ping_pong && !self.returning;
         }
 
+        let progress =
+            (self.step as i64 * ONE as i64 / self.step_number as i64) as i32;
+        let hue_span = self.hue_span as i64;
+        let hue_offset =
+            self.easing.ease(progress) as i64 * hue_span / ONE as i64;
+
+        let backward =
+            (self.direction == Direction::Backward) != self.returning;
+        let hue_offset = if backward {
+            hue_span - hue_offset
+        } else {
+            hue_offset
+        };
+
         let color = Hsv {
-            hue: self.start_color.hue
-                + ((self.step * 255) / self.step_number) as u8,
+            hue: self.start_color.hue.wrapping_add(hue_offset as u8),
             ..self.start_color
         };
         self.sequence_config.set_main_color(color.into());
-        self.step += 1;
+        self.step += self.playback.advance();
 
         Some(S::new(self.sequence_config))
     }
@@ -0,0 +1,292 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use embedded_time::{duration::Extensions, rate::Hertz};
+use rand::{distributions::Uniform, rngs::SmallRng, Rng, SeedableRng};
+use smart_leds::RGB8;
+
+use super::{
+    random_unicolor::{
+        random_color, temperature_hue_distr, TransitionTimeConfig,
+    },
+    timeline::{blend_main_and_secondary_colors, blend_main_color, Blend},
+    Chaser,
+};
+use crate::{
+    color::Interpolation,
+    easing::Easing,
+    sequence::{ConfigWithMainColor, ConfigWithSecondaryColor, Sequence},
+    time::{self, TimeConfig},
+};
+
+/// A LED chaser that performs random transitions of the main color of a
+/// sequence.
+pub struct RandomMainColor<S: Sequence<N>, const N: usize> {
+    /// The random number generator for color and transition speed selection.
+    rng: SmallRng,
+    /// The refresh rate.
+    refresh_rate: Hertz,
+    /// The hue distribution.
+    hue_distr: Uniform<i16>,
+    /// The saturation distribution.
+    sat_distr: Uniform<u8>,
+    /// The value distribution.
+    val_distr: Uniform<u8>,
+    /// The transition speed distribution.
+    transition_time_distr: Uniform<u32>,
+    /// The configuration of the transition times.
+    transition_time_config: TransitionTimeConfig,
+    /// The median transition time, once set by a timing configuration.
+    median_time_ms: Option<u32>,
+    /// The function blending two configurations.
+    blend: Blend<S::Config>,
+    /// The function setting the random colors of a configuration.
+    set_colors: SetColors<S::Config>,
+    /// The color interpolation method.
+    interpolation: Interpolation,
+    /// The easing curve of the transitions.
+    easing: Easing,
+    /// The configuration at the start of the ongoing transition.
+    start_config: S::Config,
+    /// The configuration at the end of the ongoing transition.
+    end_config: S::Config,
+    /// The number of steps of the ongoing transition.
+    step_number: u32,
+    /// The current step, counting the steps the end color is held.
+    step: u32,
+}
+
+/// A function setting the main and secondary colors of a configuration.
+type SetColors<Config> = fn(&mut Config, RGB8, RGB8);
+
+impl<S: Sequence<N>, const N: usize> Chaser<N> for RandomMainColor<S, N> {
+    fn set_time_config(&mut self, time_config: &TimeConfig) {
+        // Update the ongoing transition, keeping its duration.
+        let refresh_rate = time_config.refresh_rate.0;
+        let previous_refresh_rate = self.refresh_rate.0.max(1);
+        self.step = self.step * refresh_rate / previous_refresh_rate;
+        self.step_number =
+            self.step_number * refresh_rate / previous_refresh_rate;
+        self.refresh_rate = time_config.refresh_rate;
+
+        let median_time_ms = time_config.transition_time_ms();
+        self.median_time_ms = Some(median_time_ms);
+        self.transition_time_distr =
+            self.transition_time_config.distribution(median_time_ms);
+    }
+}
+
+impl<S: Sequence<N>, const N: usize> RandomMainColor<S, N>
+where
+    S::Config: ConfigWithMainColor,
+{
+    /// Builds a new random main color chaser.
+    pub fn new(
+        sequence_config: S::Config,
+        refresh_rate: Hertz,
+        hue_distr: Uniform<i16>,
+        transition_time_distr: Uniform<u32>,
+    ) -> Self {
+        Self::with_blend(
+            sequence_config,
+            refresh_rate,
+            hue_distr,
+            transition_time_distr,
+            blend_main_color,
+            set_main_color,
+        )
+    }
+}
+
+impl<S: Sequence<N>, const N: usize> RandomMainColor<S, N>
+where
+    S::Config: ConfigWithMainColor + ConfigWithSecondaryColor,
+{
+    /// Builds a new random main color chaser, also randomly transitioning
+    /// the secondary color.
+    pub fn with_secondary_color(
+        sequence_config: S::Config,
+        refresh_rate: Hertz,
+        hue_distr: Uniform<i16>,
+        transition_time_distr: Uniform<u32>,
+    ) -> Self {
+        Self::with_blend(
+            sequence_config,
+            refresh_rate,
+            hue_distr,
+            transition_time_distr,
+            blend_main_and_secondary_colors,
+            set_main_and_secondary_colors,
+        )
+    }
+}
+
+impl<S: Sequence<N>, const N: usize> RandomMainColor<S, N> {
+    fn with_blend(
+        sequence_config: S::Config,
+        refresh_rate: Hertz,
+        hue_distr: Uniform<i16>,
+        transition_time_distr: Uniform<u32>,
+        blend: Blend<S::Config>,
+        set_colors: SetColors<S::Config>,
+    ) -> Self {
+        let mut chaser = Self {
+            rng: SmallRng::seed_from_u64(0),
+            refresh_rate,
+            hue_distr,
+            sat_distr: Uniform::new_inclusive(255, 255),
+            val_distr: Uniform::new_inclusive(255, 255),
+            transition_time_distr,
+            transition_time_config: TransitionTimeConfig::default(),
+            median_time_ms: None,
+            blend,
+            set_colors,
+            interpolation: Interpolation::default(),
+            easing: Easing::default(),
+            start_config: sequence_config,
+            end_config: sequence_config,
+            step_number: 0,
+            step: 0,
+        };
+
+        chaser.generate_transition();
+        chaser
+    }
+
+    /// Reseeds the random number generator.
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = SmallRng::seed_from_u64(seed);
+    }
+
+    /// Sets the saturation distribution.
+    pub fn set_saturation_distr(&mut self, sat_distr: Uniform<u8>) {
+        self.sat_distr = sat_distr;
+    }
+
+    /// Sets the value distribution.
+    pub fn set_value_distr(&mut self, val_distr: Uniform<u8>) {
+        self.val_distr = val_distr;
+    }
+
+    /// Sets the color temperature.
+    ///
+    /// A negative values gives warmer hues, a positive one colder hues.
+    pub fn set_temperature(&mut self, temperature: i8) {
+        self.hue_distr = temperature_hue_distr(temperature);
+    }
+
+    /// Sets the color interpolation method.
+    pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+        self.interpolation = interpolation;
+    }
+
+    /// Sets the easing curve of the transitions.
+    pub fn set_easing(&mut self, easing: Easing) {
+        self.easing = easing;
+    }
+
+    /// Sets the configuration of the transition times.
+    ///
+    /// The spread is applied once a median time has been set by a timing
+    /// configuration.
+    pub fn set_transition_time_config(
+        &mut self,
+        transition_time_config: TransitionTimeConfig,
+    ) {
+        self.transition_time_config = transition_time_config;
+
+        if let Some(median_time_ms) = self.median_time_ms {
+            self.transition_time_distr =
+                transition_time_config.distribution(median_time_ms);
+        }
+    }
+
+    /// Starts a new transition from the end of the ongoing one.
+    fn generate_transition(&mut self) {
+        let main_color = self.random_color();
+        let secondary_color = self.random_color();
+
+        self.start_config = self.end_config;
+        (self.set_colors)(&mut self.end_config, main_color, secondary_color);
+
+        let transition_time = self
+            .transition_time_config
+            .clamp(self.rng.sample(self.transition_time_distr))
+            .milliseconds();
+        self.step_number =
+            time::steps(self.refresh_rate, transition_time).max(1);
+        self.step = 0;
+    }
+
+    fn random_color(&mut self) -> RGB8 {
+        random_color(
+            &mut self.rng,
+            &self.hue_distr,
+            &self.sat_distr,
+            &self.val_distr,
+        )
+        .into()
+    }
+
+    /// Returns the number of steps the end color is held.
+    fn hold_steps(&self) -> u32 {
+        time::steps(self.refresh_rate, self.transition_time_config.hold_time)
+    }
+}
+
+impl<S: Sequence<N>, const N: usize> Iterator for RandomMainColor<S, N> {
+    type Item = S;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step >= self.step_number + self.hold_steps() {
+            self.generate_transition();
+        }
+
+        let config = if self.step < self.step_number {
+            (self.blend)(
+                &self.start_config,
+                &self.end_config,
+                self.easing.ease_step(self.step, self.step_number),
+                self.step_number,
+                self.interpolation,
+            )
+        } else {
+            self.end_config
+        };
+
+        self.step += 1;
+
+        Some(S::new(config))
+    }
+}
+
+fn set_main_color<Config: ConfigWithMainColor>(
+    config: &mut Config,
+    main_color: RGB8,
+    _secondary_color: RGB8,
+) {
+    config.set_main_color(main_color);
+}
+
+fn set_main_and_secondary_colors<
+    Config: ConfigWithMainColor + ConfigWithSecondaryColor,
+>(
+    config: &mut Config,
+    main_color: RGB8,
+    secondary_color: RGB8,
+) {
+    config.set_main_color(main_color);
+    config.set_secondary_color(secondary_color);
+}
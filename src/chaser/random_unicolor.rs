@@ -14,154 +14,462 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use embedded_time::{duration::Extensions, rate::Hertz};
+use embedded_time::{
+    duration::{Extensions, Milliseconds},
+    rate::Hertz,
+};
 use rand::{
     distributions::{Distribution, Uniform},
     rngs::SmallRng,
-    Rng, SeedableRng,
+    Rng, RngCore, SeedableRng,
 };
 use smart_leds::{hsv::Hsv, RGB8};
 
-use super::{Chaser, UnicolorTransition};
+use super::{Chaser, ControllableChaser, UnicolorTransition};
 use crate::{
-    sequence::{Unicolor, UnicolorConfig},
-    time::TimeConfig,
+    easing::{Easing, ONE},
+    sequence::{Sequence, Unicolor, UnicolorConfig},
+    time::{self, TimeConfig},
 };
 
 /// A LED chaser that performs random transitions.
 pub struct RandomUnicolor<
     HD: Distribution<i16>,
+    SD: Distribution<u8>,
+    VD: Distribution<u8>,
     TD: Distribution<u32>,
     const N: usize,
+    R: RngCore = SmallRng,
 > {
     /// The random number generator for color and transition speed selection.
-    rng: SmallRng,
+    rng: R,
     /// The refresh rate.
     refresh_rate: Hertz,
     /// The hue distribution.
     hue_distr: HD,
+    /// The saturation distribution.
+    sat_distr: SD,
+    /// The value distribution.
+    val_distr: VD,
     /// The transition speed distribution.
     transition_time_distr: TD,
+    /// The configuration of the transition times.
+    transition_time_config: TransitionTimeConfig,
+    /// The median transition time, once set by a timing configuration.
+    median_time_ms: Option<u32>,
+    /// The number of steps the end color of the transition has been held.
+    hold_step: u32,
+    /// The easing curve of the transitions.
+    easing: Easing,
+    /// The speed multiplier of the transitions.
+    speed: u32,
     /// The ongoing transition.
     transition: UnicolorTransition<N>,
 }
 
-impl<const N: usize> Chaser<N>
-    for RandomUnicolor<Uniform<i16>, Uniform<u32>, N>
+/// The configuration of the random transition times.
+#[derive(Debug, Clone, Copy)]
+pub struct TransitionTimeConfig {
+    /// The spread of the transition times around the median time, from 0 (no
+    /// spread) to 255 (plus or minus the median time).
+    pub spread: u8,
+    /// The minimum transition time.
+    pub min_time: Milliseconds<u32>,
+    /// The maximum transition time.
+    pub max_time: Milliseconds<u32>,
+    /// The time during which the color stays still between transitions.
+    pub hold_time: Milliseconds<u32>,
+}
+
+impl Default for TransitionTimeConfig {
+    fn default() -> Self {
+        Self {
+            spread: 170,
+            min_time: Milliseconds(0),
+            max_time: Milliseconds(u32::MAX),
+            hold_time: Milliseconds(0),
+        }
+    }
+}
+
+impl TransitionTimeConfig {
+    /// Builds the transition time distribution around a median time.
+    pub fn distribution(&self, median_time_ms: u32) -> Uniform<u32> {
+        let spread = (median_time_ms as u64 * self.spread as u64 / 255) as u32;
+
+        Uniform::new_inclusive(
+            median_time_ms - spread,
+            median_time_ms.saturating_add(spread),
+        )
+    }
+
+    /// Clamps a transition time between the minimum and maximum times.
+    pub fn clamp(&self, time_ms: u32) -> u32 {
+        time_ms.max(self.min_time.0).min(self.max_time.0)
+    }
+}
+
+impl<
+        SD: Distribution<u8>,
+        VD: Distribution<u8>,
+        R: RngCore,
+        const N: usize,
+    > Chaser<N> for RandomUnicolor<Uniform<i16>, SD, VD, Uniform<u32>, N, R>
 {
     fn set_time_config(&mut self, time_config: &TimeConfig) {
         self.refresh_rate = time_config.refresh_rate;
 
-        let median_time_ms = time_config.transition_time.integer()
-            * 1000
-            * time_config.transition_time.scaling_factor().numerator()
-            / time_config.transition_time.scaling_factor().denominator();
-
-        // IDEA: Make this parametrisable.
-        let v = median_time_ms * 2 / 3;
+        let median_time_ms = time_config.transition_time_ms();
+        self.median_time_ms = Some(median_time_ms);
         self.transition_time_distr =
-            Uniform::new(median_time_ms - v, median_time_ms + v);
+            self.transition_time_config.distribution(median_time_ms);
 
         // Update the ongoing transition.
         self.transition.set_time_config(time_config);
     }
 }
 
-impl<const N: usize> RandomUnicolor<Uniform<i16>, Uniform<u32>, N> {
-    /// Builds a new random unicolor chaser.
+// NOTE: The progress is the one of the ongoing transition.
+impl<
+        SD: Distribution<u8>,
+        VD: Distribution<u8>,
+        R: RngCore,
+        const N: usize,
+    > ControllableChaser<N>
+    for RandomUnicolor<Uniform<i16>, SD, VD, Uniform<u32>, N, R>
+{
+    fn pause(&mut self) {
+        self.transition.pause();
+    }
+
+    fn resume(&mut self) {
+        self.transition.resume();
+    }
+
+    fn is_paused(&self) -> bool {
+        self.transition.is_paused()
+    }
+
+    fn seek(&mut self, progress: i32) {
+        self.transition.seek(progress);
+    }
+
+    fn progress(&self) -> i32 {
+        self.transition.progress()
+    }
+
+    fn set_speed(&mut self, speed: u32) {
+        self.speed = speed;
+        self.transition.set_speed(speed);
+    }
+}
+
+impl<const N: usize>
+    RandomUnicolor<Uniform<i16>, Uniform<u8>, Uniform<u8>, Uniform<u32>, N>
+{
+    /// Builds a new random unicolor chaser, with fully saturated colors at full
+    /// brightness.
+    ///
+    /// The random number generator is always seeded with 0, so the chaser
+    /// performs the same transitions on each run.
     pub fn new(
         refresh_rate: Hertz,
         hue_distr: Uniform<i16>,
         transition_time_distr: Uniform<u32>,
     ) -> Self {
-        let mut rng = SmallRng::seed_from_u64(0);
+        Self::with_seed(refresh_rate, hue_distr, transition_time_distr, 0)
+    }
 
+    /// Builds a new random unicolor chaser with a seeded random number
+    /// generator.
+    pub fn with_seed(
+        refresh_rate: Hertz,
+        hue_distr: Uniform<i16>,
+        transition_time_distr: Uniform<u32>,
+        seed: u64,
+    ) -> Self {
+        Self::with_rng(
+            refresh_rate,
+            hue_distr,
+            transition_time_distr,
+            SmallRng::seed_from_u64(seed),
+        )
+    }
+}
+
+impl<R: RngCore, const N: usize>
+    RandomUnicolor<Uniform<i16>, Uniform<u8>, Uniform<u8>, Uniform<u32>, N, R>
+{
+    /// Builds a new random unicolor chaser using the given random number
+    /// generator, with fully saturated colors at full brightness.
+    pub fn with_rng(
+        refresh_rate: Hertz,
+        hue_distr: Uniform<i16>,
+        transition_time_distr: Uniform<u32>,
+        rng: R,
+    ) -> Self {
+        Self::with_distributions(
+            refresh_rate,
+            hue_distr,
+            Uniform::new_inclusive(255, 255),
+            Uniform::new_inclusive(255, 255),
+            transition_time_distr,
+            rng,
+        )
+    }
+}
+
+impl<
+        SD: Distribution<u8>,
+        VD: Distribution<u8>,
+        R: RngCore,
+        const N: usize,
+    > RandomUnicolor<Uniform<i16>, SD, VD, Uniform<u32>, N, R>
+{
+    /// Builds a new random unicolor chaser using the given distributions and
+    /// random number generator.
+    pub fn with_distributions(
+        refresh_rate: Hertz,
+        hue_distr: Uniform<i16>,
+        sat_distr: SD,
+        val_distr: VD,
+        transition_time_distr: Uniform<u32>,
+        mut rng: R,
+    ) -> Self {
         let start_color = Hsv {
             hue: rng.gen(),
-            sat: 255,
-            val: 255,
+            sat: rng.sample(&sat_distr),
+            val: rng.sample(&val_distr),
         };
 
+        let end_color =
+            random_color(&mut rng, &hue_distr, &sat_distr, &val_distr);
+
+        let transition_time_config = TransitionTimeConfig::default();
+        let transition_time =
+            transition_time_config.clamp(rng.sample(transition_time_distr));
+
         let transition = generate_transition(
-            &mut rng,
             refresh_rate,
-            &hue_distr,
-            &transition_time_distr,
+            transition_time,
+            Easing::default(),
             start_color,
+            end_color,
         );
 
         Self {
             rng,
             refresh_rate,
             hue_distr,
+            sat_distr,
+            val_distr,
             transition_time_distr,
+            transition_time_config,
+            median_time_ms: None,
+            hold_step: 0,
+            easing: Easing::default(),
+            speed: ONE as u32,
             transition,
         }
     }
 
+    /// Replaces the random number generator.
+    pub fn set_rng(&mut self, rng: R) {
+        self.rng = rng;
+    }
+
+    /// Sets the saturation distribution.
+    pub fn set_saturation_distr(&mut self, sat_distr: SD) {
+        self.sat_distr = sat_distr;
+    }
+
+    /// Sets the value distribution.
+    pub fn set_value_distr(&mut self, val_distr: VD) {
+        self.val_distr = val_distr;
+    }
+
     /// Sets the color temperature.
     ///
     /// A negative values gives warmer hues, a positive one colder hues.
     pub fn set_temperature(&mut self, temperature: i8) {
-        self.hue_distr = if temperature.is_negative() {
-            Uniform::new(
-                -128 + temperature.abs() as i16,
-                127 - temperature.abs() as i16,
-            )
-        } else {
-            Uniform::new(
-                temperature.abs() as i16,
-                255 - temperature.abs() as i16,
-            )
-        };
+        self.hue_distr = temperature_hue_distr(temperature);
+    }
+
+    /// Sets the easing curve of the transitions.
+    pub fn set_easing(&mut self, easing: Easing) {
+        self.easing = easing;
+        self.transition.set_easing(easing);
+    }
+
+    /// Sets the configuration of the transition times.
+    ///
+    /// The spread is applied once a median time has been set by a timing
+    /// configuration.
+    pub fn set_transition_time_config(
+        &mut self,
+        transition_time_config: TransitionTimeConfig,
+    ) {
+        self.transition_time_config = transition_time_config;
+
+        if let Some(median_time_ms) = self.median_time_ms {
+            self.transition_time_distr =
+                transition_time_config.distribution(median_time_ms);
+        }
+    }
+}
+
+impl<
+        HD: Distribution<i16>,
+        SD: Distribution<u8>,
+        VD: Distribution<u8>,
+        TD: Distribution<u32>,
+        R: RngCore + SeedableRng,
+        const N: usize,
+    > RandomUnicolor<HD, SD, VD, TD, N, R>
+{
+    /// Reseeds the random number generator.
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
     }
 }
 
-impl<HD: Distribution<i16>, TD: Distribution<u32>, const N: usize> Iterator
-    for RandomUnicolor<HD, TD, N>
+impl<
+        HD: Distribution<i16>,
+        SD: Distribution<u8>,
+        VD: Distribution<u8>,
+        TD: Distribution<u32>,
+        R: RngCore,
+        const N: usize,
+    > Iterator for RandomUnicolor<HD, SD, VD, TD, N, R>
 {
     type Item = Unicolor<RGB8, N>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.transition.next() {
-            Some(sequence) => Some(sequence),
-            None => {
-                self.transition = generate_transition(
-                    &mut self.rng,
-                    self.refresh_rate,
-                    &self.hue_distr,
-                    &self.transition_time_distr,
-                    self.transition.end_color().into(),
-                );
-
-                self.transition.next()
+        if let Some(sequence) = self.transition.next() {
+            return Some(sequence);
+        }
+
+        let paused = self.transition.is_paused();
+
+        let hold_steps = time::steps(
+            self.refresh_rate,
+            self.transition_time_config.hold_time,
+        );
+
+        if self.hold_step < hold_steps {
+            if !paused {
+                self.hold_step += 1;
             }
+
+            return Some(Unicolor::new(UnicolorConfig {
+                color: self.transition.end_color(),
+            }));
         }
+
+        let end_color = random_color(
+            &mut self.rng,
+            &self.hue_distr,
+            &self.sat_distr,
+            &self.val_distr,
+        );
+
+        let transition_time = self
+            .transition_time_config
+            .clamp(self.rng.sample(&self.transition_time_distr));
+
+        self.transition = generate_transition(
+            self.refresh_rate,
+            transition_time,
+            self.easing,
+            self.transition.end_color().into(),
+            end_color,
+        );
+
+        self.hold_step = 0;
+        self.transition.set_speed(self.speed);
+        if paused {
+            self.transition.pause();
+        }
+
+        self.transition.next()
     }
 }
 
-fn generate_transition<const N: usize>(
+/// Returns the hue distribution for a color temperature.
+pub(super) fn temperature_hue_distr(temperature: i8) -> Uniform<i16> {
+    if temperature.is_negative() {
+        Uniform::new(
+            -128 + temperature.abs() as i16,
+            127 - temperature.abs() as i16,
+        )
+    } else {
+        Uniform::new(temperature.abs() as i16, 255 - temperature.abs() as i16)
+    }
+}
+
+/// Picks a random color.
+pub(super) fn random_color(
     rng: &mut impl Rng,
-    refresh_rate: Hertz,
     hue_distr: &impl Distribution<i16>,
-    transition_time_distr: &impl Distribution<u32>,
-    start_color: Hsv,
-) -> UnicolorTransition<N> {
-    let end_color = Hsv {
+    sat_distr: &impl Distribution<u8>,
+    val_distr: &impl Distribution<u8>,
+) -> Hsv {
+    Hsv {
         hue: rng.sample(hue_distr) as u8,
-        sat: 255,
-        val: 255,
-    };
+        sat: rng.sample(sat_distr),
+        val: rng.sample(val_distr),
+    }
+}
 
-    let transition_time = rng.sample(transition_time_distr).milliseconds();
-    let time_config = TimeConfig::new(refresh_rate, transition_time);
+fn generate_transition<const N: usize>(
+    refresh_rate: Hertz,
+    transition_time: u32,
+    easing: Easing,
+    start_color: Hsv,
+    end_color: Hsv,
+) -> UnicolorTransition<N> {
+    let time_config =
+        TimeConfig::new(refresh_rate, transition_time.milliseconds());
 
-    UnicolorTransition::new(
+    let mut transition = UnicolorTransition::new(
         UnicolorConfig {
             color: start_color.into(),
         },
         start_color,
         end_color,
         &time_config,
-    )
+    );
+
+    transition.set_easing(easing);
+    transition
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_time::duration::Extensions;
+
+    use super::*;
+
+    #[test]
+    fn full_spread_never_ends() {
+        let refresh_rate = Hertz(60);
+        let time_config = TimeConfig::new(refresh_rate, 1000.milliseconds());
+
+        let mut chaser: RandomUnicolor<_, _, _, _, 1> = RandomUnicolor::new(
+            refresh_rate,
+            Uniform::new(0, 255),
+            Uniform::new(500, 1500),
+        );
+
+        chaser.set_transition_time_config(TransitionTimeConfig {
+            spread: 255,
+            ..Default::default()
+        });
+        chaser.set_time_config(&time_config);
+
+        for _ in 0..100_000 {
+            assert!(chaser.next().is_some());
+            chaser.set_time_config(&time_config);
+        }
+    }
 }
@@ -0,0 +1,163 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use smart_leds::RGB8;
+
+use super::Chaser;
+use crate::{
+    color::{interpolate, Interpolation},
+    sequence::{
+        ConfigWithMainColor, ConfigWithSecondaryColor, Frame, FrameConfig,
+        Sequence,
+    },
+    time::TimeConfig,
+};
+
+/// A chaser in which an eye sweeps back and forth, followed by a fading tail.
+///
+/// The transition time of the timing configuration is the duration of a sweep
+/// from one end to the other.
+pub struct Scanner<const N: usize> {
+    /// The configuration.
+    config: ScannerConfig,
+    /// The number of steps in a sweep.
+    step_number: u32,
+    /// The current step, sweeping back after `step_number`.
+    step: u32,
+}
+
+/// The configuration for scanner chasers.
+#[derive(Debug, Clone, Copy)]
+pub struct ScannerConfig {
+    /// The color of the eye.
+    pub eye_color: RGB8,
+    /// The color of the background.
+    pub background_color: RGB8,
+    /// The width of the eye, in LEDs.
+    pub width: u32,
+    /// The length of the tail, in LEDs.
+    pub tail_length: u32,
+}
+
+impl<const N: usize> Chaser<N> for Scanner<N> {
+    fn set_time_config(&mut self, time_config: &TimeConfig) {
+        let step_number = time_config.transition_steps().max(1);
+        self.step = self.step * step_number / self.step_number;
+        self.step_number = step_number;
+    }
+}
+
+impl<const N: usize> Scanner<N> {
+    /// Builds a new scanner chaser.
+    pub fn new(config: ScannerConfig, time_config: &TimeConfig) -> Self {
+        Self {
+            config,
+            step_number: time_config.transition_steps().max(1),
+            step: 0,
+        }
+    }
+
+    /// Sets the configuration.
+    pub fn set_config(&mut self, config: ScannerConfig) {
+        self.config = config;
+    }
+}
+
+impl<const N: usize> Iterator for Scanner<N> {
+    type Item = Frame<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step == 2 * self.step_number {
+            self.step = 0;
+        }
+
+        // NOTE: Positions are expressed in 1/256 of LED.
+        let last_position = N.saturating_sub(1) as i64 * 256;
+        let travelled =
+            self.step as i64 * last_position / self.step_number as i64;
+        let eye_position = if self.step < self.step_number {
+            travelled
+        } else {
+            2 * last_position - travelled
+        };
+        let half_width = self.config.width as i64 * 128;
+        let tail_length = self.config.tail_length as i64 * 256;
+
+        let mut colors = [RGB8::default(); N];
+        for (i, color) in colors.iter_mut().enumerate() {
+            let x = i as i64 * 256;
+            let offset = x - eye_position;
+            // NOTE: This is the distance from the eye to the nearest edge of
+            // the LED, so that the eye is anti-aliased between LEDs.
+            let distance = offset.abs() - half_width + 128;
+
+            // The distance travelled by the eye since it passed the LED, so
+            // that the tail keeps fading when the eye turns back.
+            let age = if travelled >= 2 * last_position - x {
+                travelled - (2 * last_position - x)
+            } else if travelled >= x {
+                travelled - x
+            } else {
+                travelled + x
+            };
+            let tail_distance = age - half_width + 128;
+
+            let level = if distance <= 0 {
+                255
+            } else {
+                let tail_level = if tail_distance < tail_length {
+                    255 - tail_distance * 255 / tail_length
+                } else {
+                    0
+                };
+                let edge_level = (255 - distance).max(0);
+
+                tail_level.max(edge_level)
+            };
+
+            *color = interpolate(
+                self.config.background_color,
+                self.config.eye_color,
+                level as u32,
+                255,
+                Interpolation::Rgb,
+            );
+        }
+
+        self.step += 1;
+
+        Some(Frame::new(FrameConfig { colors }))
+    }
+}
+
+impl ConfigWithMainColor for ScannerConfig {
+    fn main_color(&self) -> RGB8 {
+        self.eye_color
+    }
+
+    fn set_main_color(&mut self, color: RGB8) {
+        self.eye_color = color;
+    }
+}
+
+impl ConfigWithSecondaryColor for ScannerConfig {
+    fn secondary_color(&self) -> RGB8 {
+        self.background_color
+    }
+
+    fn set_secondary_color(&mut self, color: RGB8) {
+        self.background_color = color;
+    }
+}
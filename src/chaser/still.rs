@@ -0,0 +1,42 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Chaser;
+use crate::{sequence::Sequence, time::TimeConfig};
+
+/// A chaser that always shows the same sequence.
+pub struct Still<S: Sequence<N>, const N: usize> {
+    /// The sequence configuration.
+    sequence_config: S::Config,
+}
+
+impl<S: Sequence<N>, const N: usize> Chaser<N> for Still<S, N> {
+    fn set_time_config(&mut self, _time_config: &TimeConfig) {}
+}
+
+impl<S: Sequence<N>, const N: usize> Still<S, N> {
+    /// Builds a new still chaser.
+    pub fn new(sequence_config: S::Config) -> Self {
+        Self { sequence_config }
+    }
+}
+
+impl<S: Sequence<N>, const N: usize> Iterator for Still<S, N> {
+    type Item = S;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(S::new(self.sequence_config))
+    }
+}
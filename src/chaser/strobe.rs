@@ -0,0 +1,162 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use embedded_time::{duration::Generic, rate::Hertz};
+use smart_leds::RGB8;
+
+use super::Chaser;
+use crate::{
+    sequence::{Frame, FrameConfig, Sequence},
+    time::{self, TimeConfig},
+};
+
+/// A chaser that flashes a sequence in bursts, showing a second sequence in
+/// between.
+///
+/// To flash a sequence with the LEDs turned off in between, use a black
+/// [`Unicolor`](crate::sequence::Unicolor) as second sequence.
+pub struct Strobe<A: Sequence<N>, B: Sequence<N>, const N: usize> {
+    /// The configuration of the flashing sequence.
+    on_config: A::Config,
+    /// The configuration of the sequence in between flashes.
+    off_config: B::Config,
+    /// The configuration.
+    config: StrobeConfig,
+    /// The refresh rate.
+    refresh_rate: Hertz,
+    /// The number of steps of a flash.
+    on_steps: u32,
+    /// The number of steps between two flashes.
+    off_steps: u32,
+    /// The number of steps between two bursts.
+    pause_steps: u32,
+    /// The current step in the burst.
+    step: u32,
+}
+
+/// The configuration for strobe chasers.
+#[derive(Debug, Clone, Copy)]
+pub struct StrobeConfig {
+    /// The duration of a flash.
+    pub on_time: Generic<u32>,
+    /// The duration between two flashes of a burst.
+    pub off_time: Generic<u32>,
+    /// The number of flashes in a burst.
+    pub burst_count: u32,
+    /// The duration of the pause after a burst.
+    pub pause_time: Generic<u32>,
+}
+
+impl<
+        A: Sequence<N, Item = RGB8>,
+        B: Sequence<N, Item = RGB8>,
+        const N: usize,
+    > Chaser<N> for Strobe<A, B, N>
+{
+    fn set_time_config(&mut self, time_config: &TimeConfig) {
+        let burst_steps = self.burst_steps();
+        self.refresh_rate = time_config.refresh_rate;
+        self.update_steps();
+        self.step = self.step * self.burst_steps() / burst_steps.max(1);
+    }
+}
+
+impl<
+        A: Sequence<N, Item = RGB8>,
+        B: Sequence<N, Item = RGB8>,
+        const N: usize,
+    > Strobe<A, B, N>
+{
+    /// Builds a new strobe chaser.
+    pub fn new(
+        on_config: A::Config,
+        off_config: B::Config,
+        config: StrobeConfig,
+        time_config: &TimeConfig,
+    ) -> Self {
+        let mut strobe = Self {
+            on_config,
+            off_config,
+            config,
+            refresh_rate: time_config.refresh_rate,
+            on_steps: 0,
+            off_steps: 0,
+            pause_steps: 0,
+            step: 0,
+        };
+
+        strobe.update_steps();
+        strobe
+    }
+
+    /// Sets the configuration.
+    pub fn set_config(&mut self, config: StrobeConfig) {
+        self.config = config;
+        self.update_steps();
+        self.step = 0;
+    }
+
+    /// Updates the number of steps from the configuration.
+    fn update_steps(&mut self) {
+        self.on_steps = time::steps(self.refresh_rate, self.config.on_time);
+        self.off_steps = time::steps(self.refresh_rate, self.config.off_time);
+        self.pause_steps =
+            time::steps(self.refresh_rate, self.config.pause_time);
+    }
+
+    /// Returns the number of steps of a burst, including the pause.
+    fn burst_steps(&self) -> u32 {
+        self.config.burst_count.max(1) * (self.on_steps + self.off_steps)
+            + self.pause_steps
+    }
+}
+
+impl<
+        A: Sequence<N, Item = RGB8>,
+        B: Sequence<N, Item = RGB8>,
+        const N: usize,
+    > Iterator for Strobe<A, B, N>
+{
+    type Item = Frame<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step >= self.burst_steps() {
+            self.step = 0;
+        }
+
+        let flash_steps = self.on_steps + self.off_steps;
+        let is_on = self.step < self.config.burst_count.max(1) * flash_steps
+            && self.step % flash_steps.max(1) < self.on_steps;
+
+        let mut colors = [RGB8::default(); N];
+        if is_on {
+            for (color, on_color) in
+                colors.iter_mut().zip(A::new(self.on_config))
+            {
+                *color = on_color;
+            }
+        } else {
+            for (color, off_color) in
+                colors.iter_mut().zip(B::new(self.off_config))
+            {
+                *color = off_color;
+            }
+        }
+
+        self.step += 1;
+
+        Some(Frame::new(FrameConfig { colors }))
+    }
+}
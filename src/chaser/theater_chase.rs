@@ -0,0 +1,122 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use smart_leds::RGB8;
+
+use super::{Chaser, Direction};
+use crate::{
+    sequence::{Frame, FrameConfig, Sequence},
+    time::TimeConfig,
+};
+
+/// A chaser in which every k-th LED of a sequence is lit, the pattern moving
+/// by one LED at each shift.
+///
+/// The transition time of the timing configuration is the duration between
+/// two shifts.
+pub struct TheaterChase<S: Sequence<N>, const N: usize> {
+    /// The sequence configuration.
+    sequence_config: S::Config,
+    /// The configuration.
+    config: TheaterChaseConfig,
+    /// The number of steps between two shifts.
+    step_number: u32,
+    /// The current step.
+    step: u32,
+    /// The current offset of the pattern.
+    offset: usize,
+}
+
+/// The configuration for theater chase chasers.
+#[derive(Debug, Clone, Copy)]
+pub struct TheaterChaseConfig {
+    /// The distance between two lit LEDs.
+    pub spacing: usize,
+    /// The direction of the pattern.
+    pub direction: Direction,
+}
+
+impl<S: Sequence<N, Item = RGB8>, const N: usize> Chaser<N>
+    for TheaterChase<S, N>
+{
+    fn set_time_config(&mut self, time_config: &TimeConfig) {
+        let step_number = time_config.transition_steps().max(1);
+        self.step = self.step * step_number / self.step_number;
+        self.step_number = step_number;
+    }
+}
+
+impl<S: Sequence<N, Item = RGB8>, const N: usize> TheaterChase<S, N> {
+    /// Builds a new theater chase chaser.
+    pub fn new(
+        sequence_config: S::Config,
+        config: TheaterChaseConfig,
+        time_config: &TimeConfig,
+    ) -> Self {
+        Self {
+            sequence_config,
+            config,
+            step_number: time_config.transition_steps().max(1),
+            step: 0,
+            offset: 0,
+        }
+    }
+
+    /// Sets the sequence configuration.
+    pub fn set_sequence_config(&mut self, sequence_config: S::Config) {
+        self.sequence_config = sequence_config;
+    }
+
+    /// Sets the configuration.
+    pub fn set_config(&mut self, config: TheaterChaseConfig) {
+        self.config = config;
+        self.offset %= config.spacing.max(1);
+    }
+}
+
+impl<S: Sequence<N, Item = RGB8>, const N: usize> Iterator
+    for TheaterChase<S, N>
+{
+    type Item = Frame<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let spacing = self.config.spacing.max(1);
+
+        if self.step == self.step_number {
+            self.step = 0;
+            self.offset = (self.offset + 1) % spacing;
+        }
+
+        let mut colors = [RGB8::default(); N];
+        for (i, (color, sequence_color)) in colors
+            .iter_mut()
+            .zip(S::new(self.sequence_config))
+            .enumerate()
+        {
+            let position = match self.config.direction {
+                Direction::Forward => i + spacing - self.offset,
+                Direction::Backward => i + self.offset,
+            };
+
+            if position % spacing == 0 {
+                *color = sequence_color;
+            }
+        }
+
+        self.step += 1;
+
+        Some(Frame::new(FrameConfig { colors }))
+    }
+}
@@ -0,0 +1,218 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use embedded_time::{duration::Milliseconds, rate::Hertz};
+
+use super::Chaser;
+use crate::{
+    color::{interpolate, Interpolation},
+    sequence::{ConfigWithMainColor, ConfigWithSecondaryColor, Sequence},
+    time::{self, TimeConfig},
+};
+
+/// A chaser that plays a timeline of sequence configurations, interpolating
+/// colors between keyframes.
+///
+/// The keyframes, sorted by time, are typically defined in a `static` table.
+///
+/// The timeline ends at its last keyframe. Only the refresh rate of the timing
+/// configuration is used.
+pub struct Timeline<'a, S: Sequence<N>, const N: usize> {
+    /// The keyframes, sorted by time.
+    keyframes: &'a [Keyframe<S::Config>],
+    /// The function blending two configurations.
+    blend: Blend<S::Config>,
+    /// The color interpolation method.
+    interpolation: Interpolation,
+    /// The refresh rate.
+    refresh_rate: Hertz,
+    /// The current step.
+    step: u32,
+}
+
+/// A keyframe in a timeline.
+#[derive(Debug, Clone, Copy)]
+pub struct Keyframe<Config> {
+    /// The time of the keyframe, from the start of the timeline.
+    pub time: Milliseconds<u32>,
+    /// The sequence configuration at this time.
+    pub config: Config,
+}
+
+/// A function blending two configurations.
+pub(super) type Blend<Config> =
+    fn(&Config, &Config, u32, u32, Interpolation) -> Config;
+
+impl<S: Sequence<N>, const N: usize> Chaser<N> for Timeline<'_, S, N> {
+    fn set_time_config(&mut self, time_config: &TimeConfig) {
+        self.step =
+            self.step * time_config.refresh_rate.0 / self.refresh_rate.0.max(1);
+        self.refresh_rate = time_config.refresh_rate;
+    }
+
+    fn restart(&mut self) {
+        self.step = 0;
+    }
+}
+
+impl<'a, S: Sequence<N>, const N: usize> Timeline<'a, S, N>
+where
+    S::Config: ConfigWithMainColor,
+{
+    /// Builds a new timeline, interpolating the main color.
+    ///
+    /// Other parameters of the configuration change at each keyframe.
+    pub fn new(
+        keyframes: &'a [Keyframe<S::Config>],
+        time_config: &TimeConfig,
+    ) -> Self {
+        Self::with_blend(keyframes, blend_main_color, time_config)
+    }
+}
+
+impl<'a, S: Sequence<N>, const N: usize> Timeline<'a, S, N>
+where
+    S::Config: ConfigWithMainColor + ConfigWithSecondaryColor,
+{
+    /// Builds a new timeline, interpolating the main and secondary colors.
+    ///
+    /// Other parameters of the configuration change at each keyframe.
+    pub fn with_secondary_color(
+        keyframes: &'a [Keyframe<S::Config>],
+        time_config: &TimeConfig,
+    ) -> Self {
+        Self::with_blend(
+            keyframes,
+            blend_main_and_secondary_colors,
+            time_config,
+        )
+    }
+}
+
+impl<'a, S: Sequence<N>, const N: usize> Timeline<'a, S, N> {
+    fn with_blend(
+        keyframes: &'a [Keyframe<S::Config>],
+        blend: Blend<S::Config>,
+        time_config: &TimeConfig,
+    ) -> Self {
+        assert!(!keyframes.is_empty());
+        assert!(keyframes
+            .windows(2)
+            .all(|pair| pair[0].time <= pair[1].time));
+
+        Self {
+            keyframes,
+            blend,
+            interpolation: Interpolation::default(),
+            refresh_rate: time_config.refresh_rate,
+            step: 0,
+        }
+    }
+
+    /// Sets the color interpolation method.
+    pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+        self.interpolation = interpolation;
+    }
+
+    /// Returns the step of a keyframe.
+    fn keyframe_step(&self, keyframe: &Keyframe<S::Config>) -> u32 {
+        time::steps(self.refresh_rate, keyframe.time)
+    }
+}
+
+impl<S: Sequence<N>, const N: usize> Iterator for Timeline<'_, S, N> {
+    type Item = S;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self
+            .keyframes
+            .iter()
+            .rposition(|keyframe| self.keyframe_step(keyframe) <= self.step);
+
+        let config = match index {
+            // Before the first keyframe.
+            None => self.keyframes[0].config,
+            Some(index) => {
+                let start = &self.keyframes[index];
+                let start_step = self.keyframe_step(start);
+
+                match self.keyframes.get(index + 1) {
+                    Some(end) => {
+                        let step_number = self.keyframe_step(end) - start_step;
+
+                        if step_number == 0 {
+                            start.config
+                        } else {
+                            (self.blend)(
+                                &start.config,
+                                &end.config,
+                                self.step - start_step,
+                                step_number,
+                                self.interpolation,
+                            )
+                        }
+                    }
+                    None if self.step <= start_step => start.config,
+                    None => return None,
+                }
+            }
+        };
+
+        self.step += 1;
+
+        Some(S::new(config))
+    }
+}
+
+/// Blends the main colors of two configurations.
+pub(super) fn blend_main_color<Config: ConfigWithMainColor>(
+    start: &Config,
+    end: &Config,
+    step: u32,
+    step_number: u32,
+    interpolation: Interpolation,
+) -> Config {
+    let mut config = *start;
+    config.set_main_color(interpolate(
+        start.main_color(),
+        end.main_color(),
+        step,
+        step_number,
+        interpolation,
+    ));
+    config
+}
+
+/// Blends the main and secondary colors of two configurations.
+pub(super) fn blend_main_and_secondary_colors<
+    Config: ConfigWithMainColor + ConfigWithSecondaryColor,
+>(
+    start: &Config,
+    end: &Config,
+    step: u32,
+    step_number: u32,
+    interpolation: Interpolation,
+) -> Config {
+    let mut config =
+        blend_main_color(start, end, step, step_number, interpolation);
+    config.set_secondary_color(interpolate(
+        start.secondary_color(),
+        end.secondary_color(),
+        step,
+        step_number,
+        interpolation,
+    ));
+    config
+}
@@ -0,0 +1,134 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use smart_leds::RGB8;
+
+use super::Chaser;
+use crate::{
+    color::{interpolate, Interpolation},
+    sequence::{Frame, FrameConfig, Sequence},
+    time::TimeConfig,
+};
+
+/// A chaser in which random LEDs twinkle on top of a sequence.
+///
+/// The transition time of the timing configuration is the duration of the
+/// fade in of a twinkle, as well as the duration of its fade out.
+pub struct Twinkle<S: Sequence<N>, const N: usize> {
+    /// The random number generator for twinkle selection.
+    rng: SmallRng,
+    /// The configuration of the background sequence.
+    sequence_config: S::Config,
+    /// The configuration.
+    config: TwinkleConfig,
+    /// The number of steps to fade in or out.
+    step_number: u32,
+    /// The current step of each LED in its twinkle, 0 meaning no twinkle.
+    steps: [u32; N],
+}
+
+/// The configuration for twinkle chasers.
+#[derive(Debug, Clone, Copy)]
+pub struct TwinkleConfig {
+    /// The color of the twinkles.
+    pub color: RGB8,
+    /// The average proportion of twinkling LEDs, out of 256.
+    pub density: u8,
+}
+
+impl<S: Sequence<N, Item = RGB8>, const N: usize> Chaser<N> for Twinkle<S, N> {
+    fn set_time_config(&mut self, time_config: &TimeConfig) {
+        let step_number = time_config.transition_steps().max(1);
+        for step in self.steps.iter_mut() {
+            *step = *step * step_number / self.step_number;
+        }
+        self.step_number = step_number;
+    }
+}
+
+impl<S: Sequence<N, Item = RGB8>, const N: usize> Twinkle<S, N> {
+    /// Builds a new twinkle chaser.
+    pub fn new(
+        sequence_config: S::Config,
+        config: TwinkleConfig,
+        time_config: &TimeConfig,
+    ) -> Self {
+        Self {
+            rng: SmallRng::seed_from_u64(0),
+            sequence_config,
+            config,
+            step_number: time_config.transition_steps().max(1),
+            steps: [0; N],
+        }
+    }
+
+    /// Sets the configuration of the background sequence.
+    pub fn set_sequence_config(&mut self, sequence_config: S::Config) {
+        self.sequence_config = sequence_config;
+    }
+
+    /// Sets the configuration.
+    pub fn set_config(&mut self, config: TwinkleConfig) {
+        self.config = config;
+    }
+}
+
+impl<S: Sequence<N, Item = RGB8>, const N: usize> Iterator for Twinkle<S, N> {
+    type Item = Frame<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // NOTE: With a probability of d / (T × (256 - d)) to start a twinkle of
+        // T steps, a proportion of d / 256 LEDs is twinkling on average.
+        let twinkle_steps = 2 * self.step_number;
+        let start_range = twinkle_steps * (256 - self.config.density as u32);
+
+        let mut colors = [RGB8::default(); N];
+        let background = S::new(self.sequence_config);
+
+        for ((color, step), background) in
+            colors.iter_mut().zip(self.steps.iter_mut()).zip(background)
+        {
+            if *step == 0
+                && self.rng.gen_range(0..start_range)
+                    < self.config.density as u32
+            {
+                *step = 1;
+            }
+
+            *color = if *step == 0 {
+                background
+            } else {
+                let level = if *step <= self.step_number {
+                    *step
+                } else {
+                    twinkle_steps - *step
+                };
+
+                *step = (*step + 1) % twinkle_steps;
+
+                interpolate(
+                    background,
+                    self.config.color,
+                    level,
+                    self.step_number,
+                    Interpolation::Rgb,
+                )
+            };
+        }
+
+        Some(Frame::new(FrameConfig { colors }))
+    }
+}
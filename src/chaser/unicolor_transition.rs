@@ -16,8 +16,13 @@
 
 use smart_leds::RGB8;
 
-use super::Chaser;
+use super::{
+    playback::{progress_to_step, step_to_progress, Playback},
+    Chaser, ControllableChaser, FiniteChaser,
+};
 use crate::{
+    color::{interpolate, Interpolation},
+    easing::Easing,
     sequence::{ConfigWithMainColor, Sequence, Unicolor, UnicolorConfig},
     time::TimeConfig,
 };
@@ -30,6 +35,12 @@ pub struct UnicolorTransition<const N: usize> {
     start_color: RGB8,
     /// The end color of the transition.
     end_color: RGB8,
+    /// The color interpolation method.
+    interpolation: Interpolation,
+    /// The easing curve.
+    easing: Easing,
+    /// The playback state.
+    playback: Playback,
     /// The number of steps to perform the transition.
     step_number: u32,
     /// The current step.
@@ -40,15 +51,65 @@ impl<const N: usize> UnicolorTransition<N> {
     pub fn end_color(&self) -> RGB8 {
         self.end_color
     }
+
+    /// Sets the color interpolation method.
+    pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+        self.interpolation = interpolation;
+    }
+
+    /// Sets the easing curve.
+    pub fn set_easing(&mut self, easing: Easing) {
+        self.easing = easing;
+    }
 }
 
 impl<const N: usize> Chaser<N> for UnicolorTransition<N> {
     // IDEA: Factorise with other implementations.
     fn set_time_config(&mut self, time_config: &TimeConfig) {
-        let step_number = time_config.transition_steps();
-        self.step = self.step * step_number / self.step_number;
+        let step_number = time_config.transition_steps().max(1);
+        self.step = self.step * step_number / self.step_number.max(1);
         self.step_number = step_number;
     }
+
+    fn restart(&mut self) {
+        self.step = 0;
+    }
+}
+
+impl<const N: usize> FiniteChaser<N> for UnicolorTransition<N> {
+    fn step_number(&self) -> u32 {
+        self.step_number
+    }
+
+    fn set_step(&mut self, step: u32) {
+        self.step = step.min(self.step_number);
+    }
+}
+
+impl<const N: usize> ControllableChaser<N> for UnicolorTransition<N> {
+    fn pause(&mut self) {
+        self.playback.pause();
+    }
+
+    fn resume(&mut self) {
+        self.playback.resume();
+    }
+
+    fn is_paused(&self) -> bool {
+        self.playback.is_paused()
+    }
+
+    fn seek(&mut self, progress: i32) {
+        self.step = progress_to_step(progress, self.step_number);
+    }
+
+    fn progress(&self) -> i32 {
+        step_to_progress(self.step, self.step_number)
+    }
+
+    fn set_speed(&mut self, speed: u32) {
+        self.playback.set_speed(speed);
+    }
 }
 
 impl<const N: usize> UnicolorTransition<N> {
@@ -62,7 +123,10 @@ impl<const N: usize> UnicolorTransition<N> {
             sequence_config,
             start_color: start_color.into(),
             end_color: end_color.into(),
-            step_number: time_config.transition_steps(),
+            interpolation: Interpolation::default(),
+            easing: Easing::default(),
+            playback: Playback::default(),
+            step_number: time_config.transition_steps().max(1),
             step: 0,
         }
     }
@@ -73,29 +137,16 @@ impl<const N: usize> Iterator for UnicolorTransition<N> {
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.step < self.step_number {
-            let color = RGB8 {
-                r: transition_step(
-                    self.start_color.r,
-                    self.end_color.r,
-                    self.step,
-                    self.step_number,
-                ),
-                g: transition_step(
-                    self.start_color.g,
-                    self.end_color.g,
-                    self.step,
-                    self.step_number,
-                ),
-                b: transition_step(
-                    self.start_color.b,
-                    self.end_color.b,
-                    self.step,
-                    self.step_number,
-                ),
-            };
+            let color = interpolate(
+                self.start_color,
+                self.end_color,
+                self.easing.ease_step(self.step, self.step_number),
+                self.step_number,
+                self.interpolation,
+            );
 
             self.sequence_config.set_main_color(color);
-            self.step += 1;
+            self.step += self.playback.advance();
 
             Some(Unicolor::new(self.sequence_config))
         } else {
@@ -103,12 +154,3 @@ impl<const N: usize> Iterator for UnicolorTransition<N> {
         }
     }
 }
-
-fn transition_step(start: u8, end: u8, step: u32, step_number: u32) -> u8 {
-    let start_i32 = start as i32;
-    let end_i32 = end as i32;
-    let step_i32 = step as i32;
-    let step_number_i32 = step_number as i32;
-
-    (start_i32 + (step_i32 * (end_i32 - start_i32)) / step_number_i32) as u8
-}
@@ -0,0 +1,183 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use smart_leds::RGB8;
+
+use super::{Chaser, FiniteChaser};
+use crate::{
+    color::{interpolate, Interpolation},
+    sequence::{Frame, FrameConfig, Sequence},
+    time::TimeConfig,
+};
+
+/// A transition in which the LEDs of a sequence are progressively replaced by
+/// the ones of another sequence.
+pub struct Wipe<A: Sequence<N>, B: Sequence<N>, const N: usize> {
+    /// The configuration of the start sequence.
+    start_config: A::Config,
+    /// The configuration of the end sequence.
+    end_config: B::Config,
+    /// The origin of the wipe.
+    origin: WipeOrigin,
+    /// The number of steps to perform the transition.
+    step_number: u32,
+    /// The current step.
+    step: u32,
+}
+
+/// The origin of a wipe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WipeOrigin {
+    /// The wipe starts from the first LED.
+    Start,
+    /// The wipe starts from the last LED.
+    End,
+    /// The wipe starts from the center, towards both ends.
+    Center,
+    /// The wipe starts from both ends, towards the center.
+    Edges,
+}
+
+impl<
+        A: Sequence<N, Item = RGB8>,
+        B: Sequence<N, Item = RGB8>,
+        const N: usize,
+    > Chaser<N> for Wipe<A, B, N>
+{
+    fn set_time_config(&mut self, time_config: &TimeConfig) {
+        let step_number = time_config.transition_steps().max(1);
+        self.step = self.step * step_number / self.step_number;
+        self.step_number = step_number;
+    }
+
+    fn restart(&mut self) {
+        self.step = 0;
+    }
+}
+
+impl<
+        A: Sequence<N, Item = RGB8>,
+        B: Sequence<N, Item = RGB8>,
+        const N: usize,
+    > FiniteChaser<N> for Wipe<A, B, N>
+{
+    fn step_number(&self) -> u32 {
+        self.step_number
+    }
+
+    fn set_step(&mut self, step: u32) {
+        self.step = step.min(self.step_number);
+    }
+}
+
+impl<
+        A: Sequence<N, Item = RGB8>,
+        B: Sequence<N, Item = RGB8>,
+        const N: usize,
+    > Wipe<A, B, N>
+{
+    /// Builds a new wipe transition.
+    pub fn new(
+        start_config: A::Config,
+        end_config: B::Config,
+        origin: WipeOrigin,
+        time_config: &TimeConfig,
+    ) -> Self {
+        Self {
+            start_config,
+            end_config,
+            origin,
+            step_number: time_config.transition_steps().max(1),
+            step: 0,
+        }
+    }
+}
+
+impl<
+        A: Sequence<N, Item = RGB8>,
+        B: Sequence<N, Item = RGB8>,
+        const N: usize,
+    > Iterator for Wipe<A, B, N>
+{
+    type Item = Frame<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step < self.step_number {
+            self.step += 1;
+
+            // NOTE: Distances are expressed in 1/256 of LED.
+            let length = N as u32 * 256;
+            let max_distance = match self.origin {
+                WipeOrigin::Start | WipeOrigin::End => length,
+                WipeOrigin::Center | WipeOrigin::Edges => length / 2,
+            };
+            let front = (self.step as u64 * max_distance as u64
+                / self.step_number as u64) as u32;
+
+            let mut colors = [RGB8::default(); N];
+            for (i, (color, (start_color, end_color))) in colors
+                .iter_mut()
+                .zip(A::new(self.start_config).zip(B::new(self.end_config)))
+                .enumerate()
+            {
+                let (near, far) =
+                    led_distances(self.origin, i as u32 * 256, length);
+                let level = front.clamp(near, far) - near;
+
+                *color = interpolate(
+                    start_color,
+                    end_color,
+                    level,
+                    far - near,
+                    Interpolation::Rgb,
+                );
+            }
+
+            Some(Frame::new(FrameConfig { colors }))
+        } else {
+            None
+        }
+    }
+}
+
+/// Computes the distances from the origin of the wipe to the nearest and
+/// farthest points of a LED.
+fn led_distances(origin: WipeOrigin, start: u32, length: u32) -> (u32, u32) {
+    let end = start + 256;
+    let center = length / 2;
+
+    match origin {
+        WipeOrigin::Start => (start, end),
+        WipeOrigin::End => (length - end, length - start),
+        WipeOrigin::Center => {
+            if end <= center {
+                (center - end, center - start)
+            } else if start >= center {
+                (start - center, end - center)
+            } else {
+                (0, (center - start).max(end - center))
+            }
+        }
+        WipeOrigin::Edges => {
+            if end <= center {
+                (start, end)
+            } else if start >= center {
+                (length - end, length - start)
+            } else {
+                (start.min(length - end), center)
+            }
+        }
+    }
+}
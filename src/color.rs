@@ -0,0 +1,112 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Utilities to deal with colors.
+
+mod gamma;
+mod oklab;
+
+pub use gamma::GammaTable;
+pub use oklab::Oklab;
+
+use smart_leds::{hsv::Hsv, RGB8};
+
+/// A color interpolation method.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Linear interpolation of each RGB channel.
+    #[default]
+    Rgb,
+    /// Interpolation in the HSV space, on the shortest path around the hue
+    /// wheel.
+    HsvShortest,
+    /// Interpolation in the HSV space, on the longest path around the hue
+    /// wheel.
+    HsvLongest,
+    /// Linear interpolation in the Oklab perceptual color space, which keeps
+    /// the perceived brightness even along the way.
+    Oklab,
+}
+
+/// Interpolates between two colors.
+///
+/// The result is `start` at step 0 and `end` at `step_number`.
+pub fn interpolate(
+    start: RGB8,
+    end: RGB8,
+    step: u32,
+    step_number: u32,
+    interpolation: Interpolation,
+) -> RGB8 {
+    match interpolation {
+        Interpolation::Rgb => RGB8 {
+            r: lerp(start.r, end.r, step, step_number),
+            g: lerp(start.g, end.g, step, step_number),
+            b: lerp(start.b, end.b, step, step_number),
+        },
+        Interpolation::HsvShortest | Interpolation::HsvLongest => {
+            let start: Hsv = start.into();
+            let end: Hsv = end.into();
+
+            // The shortest signed distance on the wheel, in -128..=127.
+            let shortest = end.hue.wrapping_sub(start.hue) as i8 as i32;
+            let hue_distance = match interpolation {
+                Interpolation::HsvLongest if shortest > 0 => shortest - 256,
+                Interpolation::HsvLongest if shortest < 0 => shortest + 256,
+                _ => shortest,
+            };
+
+            let hsv = Hsv {
+                hue: (start.hue as i32
+                    + step as i32 * hue_distance / step_number as i32)
+                    as u8,
+                sat: lerp(start.sat, end.sat, step, step_number),
+                val: lerp(start.val, end.val, step, step_number),
+            };
+
+            hsv.into()
+        }
+        Interpolation::Oklab => {
+            let start: Oklab = start.into();
+            let end: Oklab = end.into();
+
+            let oklab = Oklab {
+                l: lerp_fixed(start.l, end.l, step, step_number),
+                a: lerp_fixed(start.a, end.a, step, step_number),
+                b: lerp_fixed(start.b, end.b, step, step_number),
+            };
+
+            oklab.into()
+        }
+    }
+}
+
+fn lerp(start: u8, end: u8, step: u32, step_number: u32) -> u8 {
+    let start_i32 = start as i32;
+    let end_i32 = end as i32;
+    let step_i32 = step as i32;
+    let step_number_i32 = step_number as i32;
+
+    (start_i32 + (step_i32 * (end_i32 - start_i32)) / step_number_i32) as u8
+}
+
+fn lerp_fixed(start: i32, end: i32, step: u32, step_number: u32) -> i32 {
+    let start_i64 = start as i64;
+    let end_i64 = end as i64;
+    let step_i64 = step as i64;
+    let step_number_i64 = step_number as i64;
+
+    (start_i64 + (step_i64 * (end_i64 - start_i64)) / step_number_i64) as i32
+}
@@ -0,0 +1,82 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+/// A gamma correction lookup table.
+///
+/// The table is computed by a `const fn`, so declaring it in a `static` builds
+/// it at compile time.
+pub struct GammaTable([u8; 256]);
+
+impl GammaTable {
+    /// Builds the lookup table for a positive gamma.
+    pub const fn new(gamma: f32) -> Self {
+        let mut table = [0; 256];
+
+        let mut i = 0;
+        while i < 256 {
+            table[i] =
+                (pow(i as f64 / 255.0, gamma as f64) * 255.0 + 0.5) as u8;
+            i += 1;
+        }
+
+        Self(table)
+    }
+
+    /// Applies the gamma correction to a channel value.
+    pub fn correct(&self, value: u8) -> u8 {
+        self.0[value as usize]
+    }
+}
+
+/// Computes `base^exponent` for a base in 0.0..=1.0.
+const fn pow(base: f64, exponent: f64) -> f64 {
+    let mut result = 1.0;
+
+    let mut integer = exponent as u32;
+    while integer > 0 {
+        result *= base;
+        integer -= 1;
+    }
+
+    // NOTE: The fractional part is processed bit by bit, by multiplying with
+    // base^(1/2), base^(1/4), and so on.
+    let mut fraction = exponent - (exponent as u32) as f64;
+    let mut root = base;
+    let mut i = 0;
+    while i < 24 {
+        root = sqrt(root);
+        fraction *= 2.0;
+        if fraction >= 1.0 {
+            result *= root;
+            fraction -= 1.0;
+        }
+        i += 1;
+    }
+
+    result
+}
+
+/// Computes the square root of a positive number with Newton’s method.
+const fn sqrt(x: f64) -> f64 {
+    let mut y = if x < 1.0 { 1.0 } else { x };
+
+    let mut i = 0;
+    while i < 32 {
+        y = (y + x / y) / 2.0;
+        i += 1;
+    }
+
+    y
+}
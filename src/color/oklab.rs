@@ -0,0 +1,147 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use smart_leds::RGB8;
+
+/// A color in the Oklab perceptual color space.
+///
+/// Components are fixed-point numbers in which `1 << 16` stands for 1.0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Oklab {
+    /// The perceived lightness, from 0 to 1.0.
+    pub l: i32,
+    /// The position on the green–red axis.
+    pub a: i32,
+    /// The position on the blue–yellow axis.
+    pub b: i32,
+}
+
+impl From<RGB8> for Oklab {
+    fn from(color: RGB8) -> Self {
+        let rgb = [
+            SRGB_TO_LINEAR[color.r as usize] as i64,
+            SRGB_TO_LINEAR[color.g as usize] as i64,
+            SRGB_TO_LINEAR[color.b as usize] as i64,
+        ];
+
+        let lms = [
+            cbrt(dot([27015, 35149, 3372], rgb)),
+            cbrt(dot([13887, 44610, 7038], rgb)),
+            cbrt(dot([5787, 18463, 41286], rgb)),
+        ];
+
+        Self {
+            l: dot([13792, 52011, -267], lms) as i32,
+            a: dot([129630, -159160, 29530], lms) as i32,
+            b: dot([1698, 51300, -52997], lms) as i32,
+        }
+    }
+}
+
+impl From<Oklab> for RGB8 {
+    fn from(color: Oklab) -> Self {
+        let lab = [color.l as i64, color.a as i64, color.b as i64];
+
+        let lms = [
+            cube(dot([ONE, 25974, 14143], lab)),
+            cube(dot([ONE, -6918, -4185], lab)),
+            cube(dot([ONE, -5864, -84639], lab)),
+        ];
+
+        RGB8 {
+            r: linear_to_srgb(dot([267173, -216774, 15137], lms)),
+            g: linear_to_srgb(dot([-83128, 171033, -22369], lms)),
+            b: linear_to_srgb(dot([-275, -46099, 111913], lms)),
+        }
+    }
+}
+
+/// The fixed-point representation of 1.0.
+const ONE: i64 = 1 << 16;
+
+/// The linear intensity of each sRGB component value, in fixed point.
+const SRGB_TO_LINEAR: [u16; 256] = [
+    0, 20, 40, 60, 80, 99, 119, 139, 159, 179, 199, 219, 241, 264, 288, 313,
+    340, 367, 396, 427, 458, 491, 526, 562, 599, 637, 677, 718, 761, 805, 851,
+    898, 947, 997, 1048, 1101, 1156, 1212, 1270, 1330, 1391, 1453, 1517, 1583,
+    1651, 1720, 1790, 1863, 1937, 2013, 2090, 2170, 2250, 2333, 2418, 2504,
+    2592, 2681, 2773, 2866, 2961, 3058, 3157, 3258, 3360, 3464, 3570, 3678,
+    3788, 3900, 4014, 4129, 4247, 4366, 4488, 4611, 4736, 4864, 4993, 5124,
+    5257, 5392, 5530, 5669, 5810, 5953, 6099, 6246, 6395, 6547, 6700, 6856,
+    7014, 7174, 7335, 7500, 7666, 7834, 8004, 8177, 8352, 8528, 8708, 8889,
+    9072, 9258, 9445, 9635, 9828, 10022, 10219, 10417, 10619, 10822, 11028,
+    11235, 11446, 11658, 11873, 12090, 12309, 12530, 12754, 12980, 13209,
+    13440, 13673, 13909, 14146, 14387, 14629, 14874, 15122, 15371, 15623,
+    15878, 16135, 16394, 16656, 16920, 17187, 17456, 17727, 18001, 18277,
+    18556, 18837, 19121, 19407, 19696, 19987, 20281, 20577, 20876, 21177,
+    21481, 21787, 22096, 22407, 22721, 23038, 23357, 23678, 24002, 24329,
+    24658, 24990, 25325, 25662, 26001, 26344, 26688, 27036, 27386, 27739,
+    28094, 28452, 28813, 29176, 29542, 29911, 30282, 30656, 31033, 31412,
+    31794, 32179, 32567, 32957, 33350, 33745, 34143, 34544, 34948, 35355,
+    35764, 36176, 36591, 37008, 37429, 37852, 38278, 38706, 39138, 39572,
+    40009, 40449, 40891, 41337, 41785, 42236, 42690, 43147, 43606, 44069,
+    44534, 45002, 45473, 45947, 46423, 46903, 47385, 47871, 48359, 48850,
+    49344, 49841, 50341, 50844, 51349, 51858, 52369, 52884, 53401, 53921,
+    54445, 54971, 55500, 56032, 56567, 57105, 57646, 58190, 58737, 59287,
+    59840, 60396, 60955, 61517, 62082, 62650, 63221, 63795, 64372, 64952,
+    65535,
+];
+
+/// Computes the product of a fixed-point matrix row by a vector.
+fn dot(row: [i64; 3], vector: [i64; 3]) -> i64 {
+    (row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2]) / ONE
+}
+
+/// Computes the cube of a fixed-point number.
+fn cube(x: i64) -> i64 {
+    x * x / ONE * x / ONE
+}
+
+/// Computes the cube root of a positive fixed-point number.
+fn cbrt(x: i64) -> i64 {
+    // NOTE: cbrt(x / ONE) * ONE == cbrt(x * ONE^2), which is computed with the
+    // bitwise integer cube root algorithm.
+    let mut x = (x.max(0) as u64) << 32;
+    let mut y = 0u64;
+
+    for shift in (0..64).step_by(3).rev() {
+        y *= 2;
+        let b = 3 * y * (y + 1) + 1;
+        if x >> shift >= b {
+            x -= b << shift;
+            y += 1;
+        }
+    }
+
+    y as i64
+}
+
+/// Converts a fixed-point linear intensity to the closest sRGB value.
+fn linear_to_srgb(x: i64) -> u8 {
+    let x = x.clamp(0, u16::MAX as i64) as u16;
+    let index = SRGB_TO_LINEAR.partition_point(|&value| value < x);
+
+    match index {
+        0 => 0,
+        256 => 255,
+        _ => {
+            if SRGB_TO_LINEAR[index] - x < x - SRGB_TO_LINEAR[index - 1] {
+                index as u8
+            } else {
+                (index - 1) as u8
+            }
+        }
+    }
+}
@@ -0,0 +1,173 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Easing curves to control the pace of transitions.
+//!
+//! Easing curves work in fixed point, in which [`ONE`] stands for 1.0.
+
+/// The fixed-point representation of 1.0.
+pub const ONE: i32 = 1 << 16;
+
+/// An easing curve.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Easing {
+    /// A constant pace.
+    #[default]
+    Linear,
+    /// A quadratic acceleration.
+    QuadIn,
+    /// A quadratic deceleration.
+    QuadOut,
+    /// A quadratic acceleration, then deceleration.
+    QuadInOut,
+    /// A cubic acceleration.
+    CubicIn,
+    /// A cubic deceleration.
+    CubicOut,
+    /// A cubic acceleration, then deceleration.
+    CubicInOut,
+    /// A sinusoidal acceleration.
+    SineIn,
+    /// A sinusoidal deceleration.
+    SineOut,
+    /// A sinusoidal acceleration, then deceleration.
+    SineInOut,
+    /// A deceleration bouncing at the end, like a falling ball.
+    Bounce,
+    /// A deceleration oscillating around the end, like a spring.
+    Elastic,
+}
+
+impl Easing {
+    /// Eases a progress going from 0 to [`ONE`].
+    ///
+    /// The result goes from 0 to [`ONE`] as well, but can overshoot in between
+    /// for the elastic curve.
+    pub fn ease(self, progress: i32) -> i32 {
+        let t = progress.clamp(0, ONE) as i64;
+        let one = ONE as i64;
+
+        let eased = match self {
+            Self::Linear => t,
+            Self::QuadIn => t * t / one,
+            Self::QuadOut => one - (one - t) * (one - t) / one,
+            Self::QuadInOut => {
+                if t < one / 2 {
+                    2 * t * t / one
+                } else {
+                    one - 2 * (one - t) * (one - t) / one
+                }
+            }
+            Self::CubicIn => t * t / one * t / one,
+            Self::CubicOut => {
+                one - (one - t) * (one - t) / one * (one - t) / one
+            }
+            Self::CubicInOut => {
+                if t < one / 2 {
+                    4 * t * t / one * t / one
+                } else {
+                    one - 4 * (one - t) * (one - t) / one * (one - t) / one
+                }
+            }
+            Self::SineIn => one - cos(t / 4),
+            Self::SineOut => sin(t / 4),
+            Self::SineInOut => (one - cos(t / 2)) / 2,
+            Self::Bounce => bounce(t),
+            Self::Elastic => elastic(t),
+        };
+
+        eased as i32
+    }
+
+    /// Eases a transition step, keeping it between 0 and `step_number`.
+    pub fn ease_step(self, step: u32, step_number: u32) -> u32 {
+        let progress = (step as i64 * ONE as i64 / step_number as i64) as i32;
+        let eased = self.ease(progress).clamp(0, ONE);
+
+        // NOTE: Round to the nearest step, so that the linear easing keeps the
+        // steps unchanged despite the truncation of the progress.
+        ((eased as i64 * step_number as i64 + ONE as i64 / 2) / ONE as i64)
+            as u32
+    }
+}
+
+/// Computes the sine of an angle given in turns.
+fn sin(turns: i64) -> i64 {
+    let one = ONE as i64;
+    let turns = turns.rem_euclid(one);
+
+    // NOTE: sin(x) is computed on half a turn with Bhaskara I’s approximation,
+    // x being expressed here in half turns.
+    let (x, sign) = if turns < one / 2 {
+        (2 * turns, 1)
+    } else {
+        (2 * turns - one, -1)
+    };
+
+    let p = x * (one - x) / one;
+    sign * 16 * p * one / (5 * one - 4 * p)
+}
+
+/// Computes the cosine of an angle given in turns.
+fn cos(turns: i64) -> i64 {
+    sin(turns + ONE as i64 / 4)
+}
+
+/// Computes 2^(-x).
+fn exp2_neg(x: i64) -> i64 {
+    let one = ONE as i64;
+    let (integer, fraction) = (x / one, x % one);
+
+    if integer >= 16 {
+        0
+    } else {
+        // NOTE: 2^(-f) is approximated by 1 - 0.6716 × f + 0.1716 × f^2.
+        let power = one - 44014 * fraction / one
+            + 11246 * fraction / one * fraction / one;
+        power >> integer
+    }
+}
+
+/// Computes a bouncing deceleration.
+fn bounce(t: i64) -> i64 {
+    let one = ONE as i64;
+
+    // NOTE: The curve is made of four parabolas of slope 7.5625, which is
+    // 121 / 16, the first bounce starting at 4 / 11.
+    let (offset, base) = if t < one * 4 / 11 {
+        (0, 0)
+    } else if t < one * 8 / 11 {
+        (one * 6 / 11, one * 3 / 4)
+    } else if t < one * 10 / 11 {
+        (one * 9 / 11, one * 15 / 16)
+    } else {
+        (one * 21 / 22, one * 63 / 64)
+    };
+
+    let u = t - offset;
+    121 * u * u / (16 * one) + base
+}
+
+/// Computes an elastic deceleration.
+fn elastic(t: i64) -> i64 {
+    let one = ONE as i64;
+
+    if t == 0 || t == one {
+        t
+    } else {
+        // 2^(-10t) × sin((10t - 0.75) × 2π / 3) + 1
+        exp2_neg(10 * t) * sin((10 * t - one * 3 / 4) / 3) / one + one
+    }
+}
@@ -20,5 +20,7 @@
 #![forbid(unsafe_code)]
 
 pub mod chaser;
+pub mod color;
+pub mod easing;
 pub mod sequence;
 pub mod time;
@@ -16,14 +16,22 @@
 
 //! A collection of LED sequences on top of `smart_leds`.
 
+mod calibrated;
 mod duplicate;
+mod frame;
 mod gradient;
+mod palette_gradient;
+mod power_limited;
 mod rainbow;
 mod symmetry;
 mod unicolor;
 
+pub use calibrated::{Calibrated, CalibratedConfig};
 pub use duplicate::{Duplicate, DuplicateConfig};
+pub use frame::{Frame, FrameConfig};
 pub use gradient::{Gradient, GradientConfig};
+pub use palette_gradient::{ColorStop, PaletteGradient, PaletteGradientConfig};
+pub use power_limited::{PowerConfig, PowerLimited, PowerLimitedConfig};
 pub use rainbow::{Rainbow, RainbowConfig};
 pub use symmetry::Symmetry;
 pub use unicolor::{Unicolor, UnicolorConfig};
@@ -0,0 +1,104 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use smart_leds::RGB8;
+
+use super::{ConfigWithMainColor, ConfigWithSecondaryColor, Sequence};
+use crate::color::GammaTable;
+
+/// A sequence that calibrates the colors of another sequence for the LEDs.
+///
+/// Each color goes through a gamma correction, then each channel is scaled
+/// for white balance.
+pub struct Calibrated<S: Sequence<N>, const N: usize> {
+    /// The underlying sequence.
+    sequence: S,
+    /// The configuration.
+    config: CalibratedConfig<S::Config>,
+}
+
+/// The configuration for calibrated sequences.
+#[derive(Clone, Copy)]
+pub struct CalibratedConfig<Config> {
+    /// The configuration of the underlying sequence.
+    pub config: Config,
+    /// The gamma correction table.
+    pub gamma: &'static GammaTable,
+    /// The scale of each channel, 255 leaving the channel untouched.
+    pub white_balance: RGB8,
+}
+
+impl<S: Sequence<N, Item = RGB8>, const N: usize> Sequence<N>
+    for Calibrated<S, N>
+{
+    type Config = CalibratedConfig<S::Config>;
+
+    fn new(config: Self::Config) -> Self {
+        Self {
+            sequence: S::new(config.config),
+            config,
+        }
+    }
+
+    fn config(&self) -> Self::Config {
+        self.config
+    }
+}
+
+impl<S: Sequence<N, Item = RGB8>, const N: usize> Iterator
+    for Calibrated<S, N>
+{
+    type Item = RGB8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let color = self.sequence.next()?;
+        let gamma = self.config.gamma;
+        let white_balance = self.config.white_balance;
+
+        Some(RGB8 {
+            r: scale(gamma.correct(color.r), white_balance.r),
+            g: scale(gamma.correct(color.g), white_balance.g),
+            b: scale(gamma.correct(color.b), white_balance.b),
+        })
+    }
+}
+
+impl<Config: ConfigWithMainColor> ConfigWithMainColor
+    for CalibratedConfig<Config>
+{
+    fn main_color(&self) -> RGB8 {
+        self.config.main_color()
+    }
+
+    fn set_main_color(&mut self, color: RGB8) {
+        self.config.set_main_color(color);
+    }
+}
+
+impl<Config: ConfigWithSecondaryColor> ConfigWithSecondaryColor
+    for CalibratedConfig<Config>
+{
+    fn secondary_color(&self) -> RGB8 {
+        self.config.secondary_color()
+    }
+
+    fn set_secondary_color(&mut self, color: RGB8) {
+        self.config.set_secondary_color(color);
+    }
+}
+
+fn scale(value: u8, scale: u8) -> u8 {
+    (value as u16 * scale as u16 / 255) as u8
+}
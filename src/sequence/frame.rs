@@ -0,0 +1,59 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use smart_leds::RGB8;
+
+use super::Sequence;
+
+/// A sequence in which the color of each LED is given.
+pub struct Frame<const N: usize> {
+    /// The configuration.
+    config: FrameConfig<N>,
+    /// The counter.
+    counter: usize,
+}
+
+/// The configuration for frame sequences.
+#[derive(Debug, Clone, Copy)]
+pub struct FrameConfig<const N: usize> {
+    /// The color of each LED.
+    pub colors: [RGB8; N],
+}
+
+impl<const N: usize> Sequence<N> for Frame<N> {
+    type Config = FrameConfig<N>;
+
+    fn new(config: Self::Config) -> Self {
+        Self { config, counter: 0 }
+    }
+
+    fn config(&self) -> Self::Config {
+        self.config
+    }
+}
+
+impl<const N: usize> Iterator for Frame<N> {
+    type Item = RGB8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.counter < N {
+            let color = self.config.colors[self.counter];
+            self.counter += 1;
+            Some(color)
+        } else {
+            None
+        }
+    }
+}
@@ -17,6 +17,7 @@
 use smart_leds::RGB8;
 
 use super::{ConfigWithMainColor, ConfigWithSecondaryColor, Sequence};
+use crate::color::{interpolate, Interpolation};
 
 /// A sequence in which the LEDs draw a gradient.
 pub struct Gradient<const N: usize> {
@@ -33,6 +34,8 @@ pub struct GradientConfig {
     pub start_color: RGB8,
     /// The arrival color of the gradient.
     pub end_color: RGB8,
+    /// The color interpolation method.
+    pub interpolation: Interpolation,
 }
 
 impl<const N: usize> Sequence<N> for Gradient<N> {
@@ -52,23 +55,13 @@ impl<const N: usize> Iterator for Gradient<N> {
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.counter < N {
-            let color = RGB8 {
-                r: gradient_step::<N>(
-                    self.config.start_color.r,
-                    self.config.end_color.r,
-                    self.counter,
-                ),
-                g: gradient_step::<N>(
-                    self.config.start_color.g,
-                    self.config.end_color.g,
-                    self.counter,
-                ),
-                b: gradient_step::<N>(
-                    self.config.start_color.b,
-                    self.config.end_color.b,
-                    self.counter,
-                ),
-            };
+            let color = interpolate(
+                self.config.start_color,
+                self.config.end_color,
+                self.counter as u32,
+                N as u32 - 1,
+                self.config.interpolation,
+            );
             self.counter += 1;
             Some(color)
         } else {
@@ -96,12 +89,3 @@ impl ConfigWithSecondaryColor for GradientConfig {
         self.end_color = color;
     }
 }
-
-fn gradient_step<const N: usize>(start: u8, end: u8, step: usize) -> u8 {
-    let start_i16 = start as i16;
-    let end_i16 = end as i16;
-    let step_i16 = step as i16;
-    let led_number = N as i16;
-
-    (start_i16 + (step_i16 * (end_i16 - start_i16)) / (led_number - 1)) as u8
-}
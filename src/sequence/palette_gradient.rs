@@ -0,0 +1,128 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use smart_leds::RGB8;
+
+use super::{ConfigWithMainColor, ConfigWithSecondaryColor, Sequence};
+use crate::color::{interpolate, Interpolation};
+
+/// A sequence in which the LEDs draw a gradient through several color stops.
+pub struct PaletteGradient<const N: usize, const P: usize> {
+    /// The configuration.
+    config: PaletteGradientConfig<P>,
+    /// The counter.
+    counter: usize,
+}
+
+/// A color stop in a palette.
+#[derive(Debug, Clone, Copy)]
+pub struct ColorStop {
+    /// The position of the stop, from 0 (first LED) to 255 (last LED).
+    pub position: u8,
+    /// The color of the stop.
+    pub color: RGB8,
+}
+
+/// The configuration for palette gradient sequences.
+#[derive(Debug, Clone, Copy)]
+pub struct PaletteGradientConfig<const P: usize> {
+    /// The color stops, sorted by position.
+    pub stops: [ColorStop; P],
+}
+
+impl<const P: usize> PaletteGradientConfig<P> {
+    /// Gets the color of the palette at the given position.
+    pub fn color_at(&self, position: u8) -> RGB8 {
+        palette_color(&self.stops, position)
+    }
+}
+
+impl<const N: usize, const P: usize> Sequence<N> for PaletteGradient<N, P> {
+    type Config = PaletteGradientConfig<P>;
+
+    fn new(config: Self::Config) -> Self {
+        assert!(P > 0);
+
+        Self { config, counter: 0 }
+    }
+
+    fn config(&self) -> Self::Config {
+        self.config
+    }
+}
+
+impl<const N: usize, const P: usize> Iterator for PaletteGradient<N, P> {
+    type Item = RGB8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.counter < N {
+            let position = if N > 1 {
+                self.counter * 255 / (N - 1)
+            } else {
+                0
+            };
+
+            let color = self.config.color_at(position as u8);
+            self.counter += 1;
+            Some(color)
+        } else {
+            None
+        }
+    }
+}
+
+impl<const P: usize> ConfigWithMainColor for PaletteGradientConfig<P> {
+    fn main_color(&self) -> RGB8 {
+        self.stops[0].color
+    }
+
+    fn set_main_color(&mut self, color: RGB8) {
+        self.stops[0].color = color;
+    }
+}
+
+impl<const P: usize> ConfigWithSecondaryColor for PaletteGradientConfig<P> {
+    fn secondary_color(&self) -> RGB8 {
+        self.stops[P - 1].color
+    }
+
+    fn set_secondary_color(&mut self, color: RGB8) {
+        self.stops[P - 1].color = color;
+    }
+}
+
+fn palette_color(stops: &[ColorStop], position: u8) -> RGB8 {
+    match stops.iter().position(|stop| stop.position >= position) {
+        Some(0) => stops[0].color,
+        Some(index) => {
+            let start = stops[index - 1];
+            let end = stops[index];
+
+            // NOTE: As stops are sorted, start.position < position <=
+            // end.position, so the segment length cannot be zero.
+            let step = position - start.position;
+            let step_number = end.position - start.position;
+
+            interpolate(
+                start.color,
+                end.color,
+                step as u32,
+                step_number as u32,
+                Interpolation::Rgb,
+            )
+        }
+        None => stops[stops.len() - 1].color,
+    }
+}
@@ -0,0 +1,171 @@
+// led_effects - A collection of LED effects on top of smart-leds.
+// Copyright (C) 2026 Jean-Philippe Cugnet <jean-philippe@cugnet.eu>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3 of the License.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use smart_leds::RGB8;
+
+use super::{ConfigWithMainColor, ConfigWithSecondaryColor, Sequence};
+
+/// A sequence that limits the brightness of another sequence to fit in a power
+/// budget.
+pub struct PowerLimited<S: Sequence<N>, const N: usize> {
+    /// The configuration.
+    config: PowerLimitedConfig<S::Config>,
+    /// The limited frame.
+    frame: [RGB8; N],
+    /// The estimated current of the frame before limitation, in mA.
+    requested_current: u32,
+    /// The estimated current of the limited frame, in mA.
+    current: u32,
+    /// The counter.
+    counter: usize,
+}
+
+/// The configuration for power-limited sequences.
+#[derive(Debug, Clone, Copy)]
+pub struct PowerLimitedConfig<Config> {
+    /// The configuration of the underlying sequence.
+    pub config: Config,
+    /// The global brightness, applied before the power limitation.
+    pub brightness: u8,
+    /// The power characteristics of the LEDs.
+    pub power: PowerConfig,
+}
+
+/// The power characteristics of LEDs.
+#[derive(Debug, Clone, Copy)]
+pub struct PowerConfig {
+    /// The current drawn by a red channel at full brightness, in mA.
+    pub red_current: u32,
+    /// The current drawn by a green channel at full brightness, in mA.
+    pub green_current: u32,
+    /// The current drawn by a blue channel at full brightness, in mA.
+    pub blue_current: u32,
+    /// The current drawn by a LED, even when off, in mA.
+    pub idle_current: u32,
+    /// The maximum current for the whole sequence, in mA.
+    pub max_current: u32,
+}
+
+impl<S: Sequence<N>, const N: usize> PowerLimited<S, N> {
+    /// Returns the estimated current of the sequence before limitation, in mA.
+    pub fn requested_current(&self) -> u32 {
+        self.requested_current
+    }
+
+    /// Returns the estimated current of the sequence, in mA.
+    pub fn estimated_current(&self) -> u32 {
+        self.current
+    }
+}
+
+impl<S: Sequence<N, Item = RGB8>, const N: usize> Sequence<N>
+    for PowerLimited<S, N>
+{
+    type Config = PowerLimitedConfig<S::Config>;
+
+    fn new(config: Self::Config) -> Self {
+        let mut frame = [RGB8::default(); N];
+        for (led, color) in frame.iter_mut().zip(S::new(config.config)) {
+            *led = scale_color(color, config.brightness);
+        }
+
+        let requested_current = frame_current(&frame, &config.power);
+        let idle_current = N as u32 * config.power.idle_current;
+        let available_current =
+            config.power.max_current.saturating_sub(idle_current);
+        let channel_current = requested_current - idle_current;
+
+        if channel_current > available_current {
+            let scale = available_current * 255 / channel_current;
+
+            for led in frame.iter_mut() {
+                *led = scale_color(*led, scale as u8);
+            }
+        }
+
+        Self {
+            config,
+            frame,
+            requested_current,
+            current: frame_current(&frame, &config.power),
+            counter: 0,
+        }
+    }
+
+    fn config(&self) -> Self::Config {
+        self.config
+    }
+}
+
+impl<S: Sequence<N, Item = RGB8>, const N: usize> Iterator
+    for PowerLimited<S, N>
+{
+    type Item = RGB8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.counter < N {
+            let color = self.frame[self.counter];
+            self.counter += 1;
+            Some(color)
+        } else {
+            None
+        }
+    }
+}
+
+impl<Config: ConfigWithMainColor> ConfigWithMainColor
+    for PowerLimitedConfig<Config>
+{
+    fn main_color(&self) -> RGB8 {
+        self.config.main_color()
+    }
+
+    fn set_main_color(&mut self, color: RGB8) {
+        self.config.set_main_color(color);
+    }
+}
+
+impl<Config: ConfigWithSecondaryColor> ConfigWithSecondaryColor
+    for PowerLimitedConfig<Config>
+{
+    fn secondary_color(&self) -> RGB8 {
+        self.config.secondary_color()
+    }
+
+    fn set_secondary_color(&mut self, color: RGB8) {
+        self.config.set_secondary_color(color);
+    }
+}
+
+fn frame_current(frame: &[RGB8], power: &PowerConfig) -> u32 {
+    let channel_current: u32 = frame
+        .iter()
+        .map(|led| {
+            led.r as u32 * power.red_current
+                + led.g as u32 * power.green_current
+                + led.b as u32 * power.blue_current
+        })
+        .sum();
+
+    channel_current / 255 + frame.len() as u32 * power.idle_current
+}
+
+fn scale_color(color: RGB8, scale: u8) -> RGB8 {
+    RGB8 {
+        r: (color.r as u16 * scale as u16 / 255) as u8,
+        g: (color.g as u16 * scale as u16 / 255) as u8,
+        b: (color.b as u16 * scale as u16 / 255) as u8,
+    }
+}
@@ -37,11 +37,24 @@ impl TimeConfig {
         }
     }
 
-    /// Returns the number of steps for a transition.
-    pub fn transition_steps(&self) -> u32 {
-        self.refresh_rate.0
-            * self.transition_time.integer()
+    /// Returns the transition time in milliseconds.
+    pub fn transition_time_ms(&self) -> u32 {
+        self.transition_time.integer()
+            * 1000
             * self.transition_time.scaling_factor().numerator()
             / self.transition_time.scaling_factor().denominator()
     }
+
+    /// Returns the number of steps for a transition.
+    pub fn transition_steps(&self) -> u32 {
+        steps(self.refresh_rate, self.transition_time)
+    }
+}
+
+/// Returns the number of steps in a duration at a given refresh rate.
+pub fn steps(refresh_rate: Hertz, duration: impl Into<Generic<u32>>) -> u32 {
+    let duration = duration.into();
+
+    refresh_rate.0 * duration.integer() * duration.scaling_factor().numerator()
+        / duration.scaling_factor().denominator()
 }